@@ -1,4 +1,5 @@
-use op_gen::make_library;
+use op_gen::generate_library;
+use std::env;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::io::prelude::*;
@@ -26,6 +27,8 @@ fn main() {
     println!("cargo:rerun-if-changed={}", file.to_str().unwrap());
   }
 
+  // src/ops.rs is generated from the ops registered in the linked TensorFlow, so it isn't
+  // checked in.
   let mut file = File::create("src/ops.rs").unwrap();
   file.write_all(b"#![allow(missing_docs)]\n").unwrap();
   file.write_all(b"#![allow(non_camel_case_types)]\n").unwrap();
@@ -37,5 +40,11 @@ fn main() {
   file.write_all(b"#![allow(unused_variables)]\n").unwrap();
   file.write_all(b"#![allow(unused_mut)]\n").unwrap();
   
-  file.write_all(make_library().as_ref()).unwrap();
+  let library = generate_library();
+  file.write_all(library.code.as_ref()).unwrap();
+
+  // Ops that couldn't be generated are listed in $OUT_DIR/skipped_ops.txt
+  let report_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("skipped_ops.txt");
+  File::create(&report_path).unwrap().write_all(library.report().as_ref()).unwrap();
+  println!("Skipped {} ops, see {}", library.skipped.len(), report_path.display());
 }
\ No newline at end of file
@@ -8,6 +8,7 @@ use std::path::Path;
 use std::process::exit;
 use tensorflow::Code;
 use tensorflow::Graph;
+use tensorflow::Saver;
 use tensorflow::ImportGraphDefOptions;
 use tensorflow::Session;
 use tensorflow::SessionOptions;
@@ -60,6 +61,7 @@ fn run() -> Result<(), Box<dyn Error>> {
     let mut proto = Vec::new();
     File::open(filename)?.read_to_end(&mut proto)?;
     graph.import_graph_def(&proto, &ImportGraphDefOptions::new())?;
+    let saver = Saver::for_all_variables(&mut graph)?;
     let session = Session::new(&SessionOptions::new(), &graph)?;
     let op_x = graph.operation_by_name_required("x")?;
     let op_y = graph.operation_by_name_required("y")?;
@@ -67,9 +69,6 @@ fn run() -> Result<(), Box<dyn Error>> {
     let op_train = graph.operation_by_name_required("train")?;
     let op_w = graph.operation_by_name_required("w")?;
     let op_b = graph.operation_by_name_required("b")?;
-    let op_file_path = graph.operation_by_name_required("save/Const")?;
-    let op_save = graph.operation_by_name_required("save/control_dependency")?;
-    let file_path_tensor: Tensor<String> = Tensor::from(String::from("examples/regression_checkpoint/saved.ckpt"));
 
     // Load the test data into the session.
     let mut init_step = SessionRunArgs::new();
@@ -86,20 +85,15 @@ fn run() -> Result<(), Box<dyn Error>> {
     }
 
     // Save the model.
-    let mut step = SessionRunArgs::new();
-    step.add_feed(&op_file_path, 0, file_path_tensor.clone());
-    step.add_target(&op_save);
-    session.run(&mut step)?;
+    saver.save(&session, "examples/regression_checkpoint/saved.ckpt")?;
 
     // Initialize variables, to erase trained data.
     session.run(&mut init_step)?;
 
     // Load the model.
-    let op_load = graph.operation_by_name_required("save/restore_all")?;
-    let mut step = SessionRunArgs::new();
-    step.add_feed(&op_file_path, 0, file_path_tensor);
-    step.add_target(&op_load);
-    session.run(&mut step)?;
+    let checkpoint = tensorflow::latest_checkpoint("examples/regression_checkpoint")?
+        .ok_or_else(|| Status::new_set(Code::NotFound, "No checkpoint found").unwrap())?;
+    saver.restore(&session, checkpoint)?;
 
     // Grab the data out of the session.
     let mut output_step = SessionRunArgs::new();
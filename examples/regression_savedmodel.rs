@@ -6,11 +6,12 @@ use std::path::Path;
 use std::process::exit;
 use tensorflow::Code;
 use tensorflow::Graph;
-use tensorflow::Session;
+use tensorflow::SavedModelBundle;
 use tensorflow::SessionOptions;
 use tensorflow::SessionRunArgs;
 use tensorflow::Status;
 use tensorflow::Tensor;
+use tensorflow::REGRESS_METHOD_NAME;
 
 #[cfg_attr(feature="examples_system_alloc", global_allocator)]
 #[cfg(feature="examples_system_alloc")]
@@ -54,20 +55,22 @@ fn run() -> Result<(), Box<dyn Error>> {
 
     // Load the saved model exported by regression_savedmodel.py.
     let mut graph = Graph::new();
-    let session = Session::from_saved_model(&SessionOptions::new(),
-                                            &["train", "serve"],
-                                            &mut graph,
-                                            export_dir)?;
-    let op_x = graph.operation_by_name_required("x")?;
-    let op_y = graph.operation_by_name_required("y")?;
+    let bundle = SavedModelBundle::load(&SessionOptions::new(),
+                                        &["train", "serve"],
+                                        &mut graph,
+                                        export_dir)?;
+    let session = &bundle.session;
+    let signature = bundle.signature(REGRESS_METHOD_NAME)?;
+    let x_input = signature.input("x")?;
+    let y_input = signature.input("y")?;
     let op_train = graph.operation_by_name_required("train")?;
     let op_w = graph.operation_by_name_required("w")?;
     let op_b = graph.operation_by_name_required("b")?;
 
     // Train the model (e.g. for fine tuning).
     let mut train_step = SessionRunArgs::new();
-    train_step.add_feed(&op_x, 0, x);
-    train_step.add_feed(&op_y, 0, y);
+    train_step.add_feed(&x_input.operation, x_input.index, x);
+    train_step.add_feed(&y_input.operation, y_input.index, y);
     train_step.add_target(&op_train);
     for _ in 0..steps {
         session.run(&mut train_step)?;
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use tensorflow_protos::op_def::OpDef_ArgDef;
 use tensorflow_protos::types::DataType;
 
@@ -7,6 +8,14 @@ use super::op_impl::{AddToImpl, OpImpl, OpLib, Function};
 
 use codegen as cg;
 
+/// Maps the name of each `list(type)` attr of an op to an expression, in terms of `rc`,
+/// that evaluates to the `Vec<DataType>` the attr will hold once the op is built.
+pub(crate) type TypeLists = HashMap<String, String>;
+
+/// Maps the name of each `int` attr of an op to an expression, in terms of `rc`,
+/// that evaluates to the attr's value as a `usize`. Used to size `number_attr` outputs.
+pub(crate) type NumberAttrs = HashMap<String, String>;
+
 pub(crate) fn make_input_arg(arg: OpDef_ArgDef) -> Result<Box<AddToImpl>, String> {
     let field_type = arg.get_field_type();
     let type_attr = arg.get_type_attr();
@@ -15,24 +24,99 @@ pub(crate) fn make_input_arg(arg: OpDef_ArgDef) -> Result<Box<AddToImpl>, String
     match (field_type, type_attr, type_list_attr) {
         (_, "", "") => Ok(Box::new(ConcreteInputArg::new(arg))),
         (DataType::DT_INVALID, _, "") => Ok(Box::new(GenericInputArg::new(arg))),
-        (DataType::DT_INVALID, "", _) => Err("Type list not currently supported".to_string()),
+        (DataType::DT_INVALID, "", _) => Ok(Box::new(TypeListInputArg::new(arg))),
         _ => return Err("Invalid type combination".to_string()),
     }
 }
 
-pub(crate) fn make_output_arg(arg: OpDef_ArgDef, port: usize) -> Result<Box<AddToImpl>, String> {
+pub(crate) fn make_output_arg(arg: OpDef_ArgDef,
+                               port: &Port,
+                               type_lists: &TypeLists,
+                               number_attrs: &NumberAttrs) -> Result<Box<AddToImpl>, String> {
     let field_type = arg.get_field_type();
     let type_attr = arg.get_type_attr();
     let type_list_attr = arg.get_type_list_attr();
+    let number_attr = arg.get_number_attr();
+
+    if !number_attr.is_empty() {
+        let count = match number_attrs.get(number_attr) {
+            Some(count) => count.clone(),
+            None => return Err(format!("Unknown number attr {}", number_attr)),
+        };
+        return match (field_type, type_attr, type_list_attr) {
+            (_, "", "") | (DataType::DT_INVALID, _, "") => Ok(Box::new(ListOutputArg::new(arg, port.usize_expr(), count))),
+            _ => Err("Invalid type combination".to_string()),
+        };
+    }
 
     match (field_type, type_attr, type_list_attr) {
-        (_, "", "") => Ok(Box::new(ConcreteOutputArg::new(arg, port))),
-        (DataType::DT_INVALID, _, "") => Ok(Box::new(GenericOutputArg::new(arg, port))),
-        (DataType::DT_INVALID, "", _) => Err("Type list not currently supported".to_string()),
+        (_, "", "") => Ok(Box::new(ConcreteOutputArg::new(arg, port.expr()))),
+        (DataType::DT_INVALID, _, "") => Ok(Box::new(GenericOutputArg::new(arg, port.expr()))),
+        (DataType::DT_INVALID, "", _) => {
+            let dtypes = match type_lists.get(type_list_attr) {
+                Some(dtypes) => dtypes.clone(),
+                None => return Err(format!("Unknown type list attr {}", type_list_attr)),
+            };
+            Ok(Box::new(TypeListOutputArg::new(arg, port.usize_expr(), dtypes)))
+        },
         _ => return Err("Invalid type combination".to_string()),
     }
 }
 
+/// Tracks the index of the next output of an op. Outputs that come after a list output
+/// have ports that depend on the length of that list, so they can't always be known
+/// when the code is generated.
+#[derive(Clone, Default)]
+pub(crate) struct Port {
+    fixed: usize,
+    dynamic: Vec<String>,
+}
+
+impl Port {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// The port as a `usize` expression
+    fn usize_expr(&self) -> String {
+        let mut terms = vec![self.fixed.to_string()];
+        terms.extend(self.dynamic.iter().cloned());
+        terms.join(" + ")
+    }
+
+    /// The port as a `c_int` expression, suitable for passing to `Edge::new`
+    fn expr(&self) -> String {
+        if self.dynamic.is_empty() {
+            self.fixed.to_string()
+        } else {
+            format!("({}) as c_int", self.usize_expr())
+        }
+    }
+
+    /// Moves past the outputs produced by `arg`
+    pub(crate) fn advance(&mut self,
+                          arg: &OpDef_ArgDef,
+                          type_lists: &TypeLists,
+                          number_attrs: &NumberAttrs) -> Result<(), String> {
+        let type_list_attr = arg.get_type_list_attr();
+        let number_attr = arg.get_number_attr();
+        if !number_attr.is_empty() {
+            match number_attrs.get(number_attr) {
+                Some(count) => self.dynamic.push(count.clone()),
+                None => return Err(format!("Unknown number attr {}", number_attr)),
+            }
+        } else if !type_list_attr.is_empty() {
+            match type_lists.get(type_list_attr) {
+                Some(dtypes) => self.dynamic.push(format!("{}.len()", dtypes)),
+                None => return Err(format!("Unknown type list attr {}", type_list_attr)),
+            }
+        } else {
+            self.fixed += 1;
+        }
+        Ok(())
+    }
+}
+
 struct GenericInputArg {
     arg: Arg,
 }
@@ -103,13 +187,48 @@ impl AddToImpl for ConcreteInputArg {
 }
 
 
+/// An input made up of edges of differing types, e.g. the tensors passed to `SaveV2`.
+/// The `list(type)` attr describing the input is inferred from the edges.
+struct TypeListInputArg {
+    arg: Arg,
+}
+
+impl TypeListInputArg {
+    fn new(arg: OpDef_ArgDef) -> Self {
+        Self {
+            arg: Arg::new(arg),
+        }
+    }
+}
+
+impl AddToImpl for TypeListInputArg {
+    fn add_to_impl(&self, impl_: &mut OpImpl, lib: &mut OpLib) -> Result<(), String> {
+        impl_.builder.new_fn_arg(&self.arg.name()?, &cg::Type::new("&[AnyEdge]"));
+        impl_.builder.make_self.line(&format!("{}: {}.to_vec(),", self.arg.name()?, self.arg.name()?));
+        impl_.builder.struct_.field(&self.arg.name()?, "Vec<AnyEdge>");
+
+        let mut setup = cg::Block::new("");
+        setup.line(&format!("new_op.set_attr_type_list(\"{}\", &{})?;",
+                            self.arg.arg.get_type_list_attr(),
+                            inferred_type_list(&self.arg.name()?, "self")))
+             .line(&format!("new_op.add_any_edge_list(&self.{})?", &self.arg.name()?));
+        impl_.builder.add_op_description_setup(setup);
+        Ok(())
+    }
+}
+
+/// Expression for the types of the edges held in a type list input arg
+pub(crate) fn inferred_type_list(arg_name: &str, owner: &str) -> String {
+    format!("{}.{}.iter().map(|edge| edge.data_type()).collect::<Vec<DataType>>()", owner, arg_name)
+}
+
 struct ConcreteOutputArg {
     arg: Arg,
-    port: usize,
+    port: String,
 }
 
 impl ConcreteOutputArg {
-    fn new(arg: OpDef_ArgDef, port: usize) -> Self {
+    fn new(arg: OpDef_ArgDef, port: String) -> Self {
         Self {
             arg: Arg::new(arg),
             port,
@@ -128,11 +247,11 @@ impl AddToImpl for ConcreteOutputArg {
 
 struct GenericOutputArg {
     arg: Arg,
-    port: usize,
+    port: String,
 }
 
 impl GenericOutputArg {
-    fn new(arg: OpDef_ArgDef, port: usize) -> Self {
+    fn new(arg: OpDef_ArgDef, port: String) -> Self {
         Self {
             arg: Arg::new(arg),
             port,
@@ -151,6 +270,71 @@ impl AddToImpl for GenericOutputArg {
     }
 }
 
+/// An output made up of a number of edges of the same type, e.g. the pieces returned
+/// by `Split`. The number of edges is given by an `int` attr.
+struct ListOutputArg {
+    arg: Arg,
+    port: String,
+    count: String,
+}
+
+impl ListOutputArg {
+    fn new(arg: OpDef_ArgDef, port: String, count: String) -> Self {
+        Self {
+            arg: Arg::new(arg),
+            port,
+            count,
+        }
+    }
+}
+
+impl AddToImpl for ListOutputArg {
+    fn add_to_impl(&self, impl_: &mut OpImpl, lib: &mut OpLib) -> Result<(), String> {
+        let edge_type = self.arg.output_edge_type()?;
+        let mut output_block = cg::Block::new("");
+        output_block.line(format!("(0..{})", self.count))
+                    .line(format!("    .map(|i| {}::new(rc.clone(), ({} + i) as c_int))", edge_type.turbo_fish(), self.port))
+                    .line(format!("    .collect::<Vec<{}>>()", edge_type));
+        impl_.add_output(wrap_type("Vec", edge_type), output_block, &self.arg.name()?);
+        if !self.arg.arg.get_type_attr().is_empty() {
+            impl_.generic(&self.arg.type_attr()?);
+            impl_.bound(&self.arg.type_attr()?, "TensorType");
+        }
+        Ok(())
+    }
+}
+
+/// An output made up of edges of differing types, e.g. the tensors returned by `RestoreV2`.
+/// One edge is returned for each type in the `list(type)` attr describing the output.
+struct TypeListOutputArg {
+    arg: Arg,
+    port: String,
+    dtypes: String,
+}
+
+impl TypeListOutputArg {
+    fn new(arg: OpDef_ArgDef, port: String, dtypes: String) -> Self {
+        Self {
+            arg: Arg::new(arg),
+            port,
+            dtypes,
+        }
+    }
+}
+
+impl AddToImpl for TypeListOutputArg {
+    fn add_to_impl(&self, impl_: &mut OpImpl, lib: &mut OpLib) -> Result<(), String> {
+        let mut output_block = cg::Block::new("");
+        output_block.line(format!("let dtypes: Vec<DataType> = {};", self.dtypes))
+                    .line("dtypes.into_iter()")
+                    .line("      .enumerate()")
+                    .line(format!("      .map(|(i, dtype)| AnyEdge::new(rc.clone(), ({} + i) as c_int, dtype))", self.port))
+                    .line("      .collect::<Vec<AnyEdge>>()");
+        impl_.add_output(cg::Type::new("Vec<AnyEdge>"), output_block, &self.arg.name()?);
+        Ok(())
+    }
+}
+
 pub(crate) struct Arg {
     arg: OpDef_ArgDef,
 }
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use tensorflow_protos::types::DataType;
 use tensorflow_protos::op_def::OpDef_AttrDef;
 use tensorflow_protos::attr_value::{AttrValue, AttrValue_oneof_value};
@@ -434,6 +435,44 @@ impl AttrBootstrap for FuncAttr {
 }
 
 
+/// A `list(type)` attr that has to be given explicitly, e.g. the `dtypes` of `RestoreV2`.
+/// Lists that describe an input are inferred from the input's edges instead.
+struct TypeListAttr {
+    attr: Attr,
+}
+
+impl AttrBootstrap for TypeListAttr {
+    fn attr(&self) -> &Attr {
+        &self.attr
+    }
+
+    fn base_type(&self) -> Result<String, String> {
+        Ok("DataType".to_string())
+    }
+
+    fn setup(&self) -> Result<String, String> {
+        Ok(format!("(|attrs| {{new_op.set_attr_type_list(\"{}\", attrs)}})",
+                    self.attr_name()))
+    }
+
+    fn builder_arg_type(&self) -> Result<String, String> {
+        Ok("&[DataType]".to_string())
+    }
+
+    fn builder_block(&self) -> Result<String, String> {
+        Ok(format!("{}.to_vec()", self.escaped_name()?))
+    }
+}
+
+/// Type list attrs which are set by the input that they describe
+struct InferredTypeListAttr;
+
+impl AddToImpl for InferredTypeListAttr {
+    fn add_to_impl(&self, impl_: &mut OpImpl, lib: &mut OpLib) -> Result<(), String> {
+        Ok(())
+    }
+}
+
 struct TypeAttr {
     attr: Attr,
 }
@@ -460,8 +499,14 @@ impl AddToImpl for TypeAttr {
 }
 
 
-pub(crate) fn make_attr(attr: OpDef_AttrDef) -> Result<Box<dyn AddToImpl>, String> {
+pub(crate) fn make_attr(attr: OpDef_AttrDef, inferred_type_lists: &HashSet<String>) -> Result<Box<dyn AddToImpl>, String> {
     let attr = Attr::new(attr);
+    if attr.is_list() && attr.bare_type_name() == "type" {
+        if inferred_type_lists.contains(attr.tf_name()) {
+            return Ok(Box::new(InferredTypeListAttr));
+        }
+        return Ok(Box::new(TypeListAttr {attr}));
+    }
     match attr.bare_type_name() {
         "string" => Ok(Box::new(StringAttr {attr})),
         "float" => Ok(Box::new(FloatAttr {attr})),
@@ -482,7 +527,7 @@ pub(crate) struct Attr {
 }
 
 impl Attr {
-    fn new(attr: OpDef_AttrDef) -> Self {
+    pub(crate) fn new(attr: OpDef_AttrDef) -> Self {
         Self {
             attr,
         }
@@ -519,7 +564,37 @@ impl Attr {
         self.attr.has_default_value()
     }
 
-    fn bare_type_name(&self) -> &str {
+    /// Expression, in terms of `owner`, for the value of an `int` attr as a `usize`
+    pub(crate) fn list_length(&self, owner: &str) -> Result<String, String> {
+        let name = self.name()?;
+        if self.has_default_value() {
+            Ok(format!("{}.{}.unwrap_or({}) as usize", owner, name, self.attr.get_default_value().get_i()))
+        } else {
+            Ok(format!("{}.{} as usize", owner, name))
+        }
+    }
+
+    /// Expression, in terms of `owner`, for the types a `list(type)` attr will be set to
+    pub(crate) fn type_list(&self, owner: &str) -> Result<String, String> {
+        let name = self.name()?;
+        if !self.has_default_value() {
+            return Ok(format!("{}.{}.clone()", owner, name));
+        }
+
+        let default_value = self.attr.get_default_value();
+        let defaults: Vec<String> = default_value.get_list()
+                                                 .get_field_type()
+                                                 .iter()
+                                                 .map(|x| format!("DataType::from_int({})", *x as u32))
+                                                 .collect();
+        if defaults.is_empty() {
+            Ok(format!("{}.{}.clone().unwrap_or_default()", owner, name))
+        } else {
+            Ok(format!("{}.{}.clone().unwrap_or_else(|| vec![{}])", owner, name, defaults.join(", ")))
+        }
+    }
+
+    pub(crate) fn bare_type_name(&self) -> &str {
         if self.is_list() {
             let end = self.attr.get_field_type().len();
             &self.attr.get_field_type()[5..end-1]
@@ -528,7 +603,7 @@ impl Attr {
         }
     }
 
-    fn is_list(&self) -> bool {
+    pub(crate) fn is_list(&self) -> bool {
         self.attr.get_field_type().starts_with("list(") && self.attr.get_field_type().ends_with(")")
     }
 
@@ -585,4 +660,9 @@ impl Attr {
     fn name(&self) -> Result<String, String> {
         Ok(escape_keyword(self.attr.get_name()))
     }
+
+    /// Name used by tensorflow
+    pub(crate) fn tf_name(&self) -> &str {
+        self.attr.get_name()
+    }
 }
@@ -1,4 +1,4 @@
-use op_gen::make_library;
+use op_gen::generate_library;
 use std::fs::File;
 use std::io::prelude::*;
 
@@ -7,5 +7,9 @@ fn main() {
   let mut file = File::create("test1.rs").unwrap();
   file.write_all(b"#![allow(dead_code)]\n").unwrap();
   file.write_all(b"#![allow(non_camel_case_types)]\n").unwrap();
-  file.write_all(make_library().as_ref()).unwrap();
+  let library = generate_library();
+  file.write_all(library.code.as_ref()).unwrap();
+
+  let mut report = File::create("skipped_ops.txt").unwrap();
+  report.write_all(library.report().as_ref()).unwrap();
 }
\ No newline at end of file
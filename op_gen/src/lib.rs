@@ -8,10 +8,13 @@ pub mod utils;
 pub mod arg;
 pub mod op_impl;
 
+use std::collections::HashSet;
+use std::fmt;
+
 use op_impl::{OpLib, AddToLib, OpImpl};
 use utils::{escape_keyword};
-use arg::{make_input_arg, make_output_arg};
-use attr::make_attr;
+use arg::{make_input_arg, make_output_arg, inferred_type_list, Port, TypeLists, NumberAttrs};
+use attr::{make_attr, Attr};
 use op_list::load_oplist;
 
 struct Op {
@@ -28,6 +31,46 @@ impl Op {
     fn name(&self) -> String {
         escape_keyword(self.op.get_name())
     }
+
+    /// Names of the `list(type)` attrs that are inferred from an input
+    fn inferred_type_lists(&self) -> HashSet<String> {
+        self.op.get_input_arg()
+               .iter()
+               .map(|arg| arg.get_type_list_attr().to_string())
+               .filter(|attr| !attr.is_empty())
+               .collect()
+    }
+
+    /// Where the types of each `list(type)` attr come from once the op is built
+    fn type_lists(&self) -> Result<TypeLists, String> {
+        let mut type_lists = TypeLists::new();
+        for attr in self.op.get_attr() {
+            let attr = Attr::new(attr.clone());
+            if attr.is_list() && attr.bare_type_name() == "type" {
+                type_lists.insert(attr.tf_name().to_string(), attr.type_list("rc")?);
+            }
+        }
+        for input_arg in self.op.get_input_arg() {
+            let type_list_attr = input_arg.get_type_list_attr();
+            if !type_list_attr.is_empty() {
+                type_lists.insert(type_list_attr.to_string(),
+                                  inferred_type_list(&escape_keyword(input_arg.get_name()), "rc"));
+            }
+        }
+        Ok(type_lists)
+    }
+
+    /// The values of the `int` attrs, which give the lengths of `number_attr` outputs
+    fn number_attrs(&self) -> Result<NumberAttrs, String> {
+        let mut number_attrs = NumberAttrs::new();
+        for attr in self.op.get_attr() {
+            let attr = Attr::new(attr.clone());
+            if !attr.is_list() && attr.bare_type_name() == "int" {
+                number_attrs.insert(attr.tf_name().to_string(), attr.list_length("rc")?);
+            }
+        }
+        Ok(number_attrs)
+    }
 }
 
 impl AddToLib for Op {
@@ -37,26 +80,72 @@ impl AddToLib for Op {
             make_input_arg(input_arg.clone())?.add_to_impl(&mut impl_, lib)?;
         }
 
-        for (port, output_arg) in self.op.get_output_arg().into_iter().enumerate() {
-            make_output_arg(output_arg.clone(), port)?.add_to_impl(&mut impl_, lib)?;
+        let type_lists = self.type_lists()?;
+        let number_attrs = self.number_attrs()?;
+        let mut port = Port::new();
+        for output_arg in self.op.get_output_arg() {
+            make_output_arg(output_arg.clone(), &port, &type_lists, &number_attrs)?.add_to_impl(&mut impl_, lib)?;
+            port.advance(output_arg, &type_lists, &number_attrs)?;
         }
 
+        let inferred_type_lists = self.inferred_type_lists();
         for attr in self.op.get_attr() {
-            make_attr(attr.clone())?.add_to_impl(&mut impl_, lib)?;
+            make_attr(attr.clone(), &inferred_type_lists)?.add_to_impl(&mut impl_, lib)?;
         }
         impl_.finish(lib)?;
         Ok(())
     }
 }
 
-pub fn make_library() -> String {
+/// An op that `make_library` was unable to generate code for.
+#[derive(Debug, Clone)]
+pub struct SkippedOp {
+    pub name: String,
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.reason)
+    }
+}
+
+/// Generated code for all the registered ops, along with the ops that were left out.
+pub struct Library {
+    pub code: String,
+    pub skipped: Vec<SkippedOp>,
+}
+
+impl Library {
+    /// One line per skipped op, giving the reason it was skipped
+    pub fn report(&self) -> String {
+        let mut report = format!("{} ops skipped\n", self.skipped.len());
+        for op in &self.skipped {
+            report.push_str(&format!("{}\n", op));
+        }
+        report
+    }
+}
+
+pub fn generate_library() -> Library {
     let op_list = load_oplist();
     let mut op_lib = OpLib::new();
+    let mut skipped = Vec::new();
     for op_def in op_list.get_op() {
         let op = Op::new(op_def.clone());
-        if let Err(msg) = op.add_to_lib(&mut op_lib) {
-            println!("Not adding op {} because {}", op.name(), msg);
+        if let Err(reason) = op.add_to_lib(&mut op_lib) {
+            skipped.push(SkippedOp {
+                name: op.name(),
+                reason,
+            });
         }
     }
-    op_lib.string()
+    Library {
+        code: op_lib.string(),
+        skipped,
+    }
+}
+
+pub fn make_library() -> String {
+    generate_library().code
 }
\ No newline at end of file
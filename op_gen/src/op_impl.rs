@@ -33,6 +33,7 @@ impl OpLib {
         lib.scope.import("super::graph", "RefEdge");
         lib.scope.import("super::graph", "GraphEdge");
         lib.scope.import("super::graph", "GraphRefEdge");
+        lib.scope.import("super::graph", "AnyEdge");
         lib.scope.import("super", "Shape as OtherShape");
         lib.scope.import("super", "new_id");
         lib.scope.import("super", "TensorType");
@@ -46,6 +47,9 @@ impl OpLib {
         lib.scope.import("std::convert", "From");
         lib.scope.import("std::marker", "PhantomData");
         lib.scope.import("super", "Result");
+        lib.scope.import("super", "DataType");
+        lib.scope.import("super", "ResourceHandle");
+        lib.scope.import("libc", "c_int");
         lib
     }
 
@@ -207,28 +211,37 @@ impl Builder {
                .ret(format!("Result<Operation>"))
                .line("if let Some(x) = graph.get_op_by_id(self.get_id()) {")
                .line("    return Ok(x);")
-               .line("}")
-               .line("let op_name = match &self.op_name {")
-               .line("    Some(name) => name.clone(),")
-               .line(format!("    None => graph.new_op_name(\"{}_{{}}\")?", &self.op_name))
-               .line("};")
-               .line("let mut control_inputs = Vec::new();")
-               .line("for control_input in self.control_inputs.iter() {")
-               .line("    control_inputs.push(control_input.tf_operation(graph)?);")
-               .line("}")
-               .line(format!("let mut new_op = graph.new_operation(\"{}\", &op_name)?;", &self.op_name))
-               .line("for control_input in control_inputs {")
-               .line("    new_op.add_control_input(&control_input)")
                .line("}");
 
+        // The operation is added outside any conditional branch it was created outside of, so
+        // only its uses inside a branch are switched into it.
+        let mut add_op = cg::Block::new("graph.outside_cond_contexts(self.get_id(), |graph| -> Result<Operation>");
+        add_op.line("let op_name = match &self.op_name {")
+              .line("    Some(name) => graph.scoped_name(name),")
+              .line(format!("    None => graph.new_op_name(&graph.scoped_name(\"{}_{{}}\"))?", &self.op_name))
+              .line("};")
+              .line("let device = graph.device();")
+              .line("let mut control_inputs = Vec::new();")
+              .line("for control_input in self.control_inputs.iter() {")
+              .line("    control_inputs.push(control_input.tf_operation(graph)?);")
+              .line("}")
+              .line(format!("let mut new_op = graph.new_operation(\"{}\", &op_name)?;", &self.op_name))
+              .line("if let Some(device) = device {")
+              .line("    new_op.set_device(&device)?;")
+              .line("}")
+              .line("for control_input in control_inputs {")
+              .line("    new_op.add_control_input(&control_input)")
+              .line("}");
 
         for block in &self.op_description_setup {
-            make_op.push_block(block.clone());
+            add_op.push_block(block.clone());
         }
 
-        make_op.line("let op = new_op.finish()?;");
-        make_op.line("graph.record_op(self.get_id(), op.clone());");
-        make_op.line("Ok(op)");
+        add_op.line("let op = new_op.finish()?;");
+        add_op.line("graph.record_op(self.get_id(), op.clone());");
+        add_op.line("Ok(op)");
+        add_op.after(")");
+        make_op.push_block(add_op);
         Ok(make_op)
     }
 
@@ -279,6 +292,43 @@ impl Builder {
         Ok(make_finish)
     }
 
+    /// Make a struct holding every output of an op with more than one, and the `outputs` function
+    /// returning it, so all the outputs can be used without building the op more than once
+    fn make_outputs(&self, generics: &[String]) -> Option<(cg::Struct, cg::Function)> {
+        if self.outputs.len() < 2 {
+            return None;
+        }
+
+        let name = format!("{}Outputs", self.name);
+        let mut outputs_struct = cg::Struct::new(&name);
+        outputs_struct.vis("pub")
+                      .derive("Clone");
+        for generic in generics {
+            outputs_struct.generic(generic)
+                          .bound(generic, "TensorType");
+        }
+
+        let ret = if generics.is_empty() {
+            name.clone()
+        } else {
+            format!("{}<{}>", name, join_vec(&generics.to_vec(), ", "))
+        };
+        let names: Vec<String> = self.outputs.iter().map(|x| x.2.clone()).collect();
+        let mut make_struct = cg::Block::new(&name);
+        for (ty, _, output_name) in &self.outputs {
+            outputs_struct.field(&format!("pub {}", output_name), ty.clone());
+            make_struct.line(format!("{},", output_name));
+        }
+
+        let mut outputs_fn = cg::Function::new("outputs");
+        outputs_fn.arg_self()
+                  .vis("pub")
+                  .ret(ret.as_str())
+                  .line(format!("let ({}) = self.finish();", join_vec(&names, ", ")))
+                  .push_block(make_struct);
+        Some((outputs_struct, outputs_fn))
+    }
+
     /// Allow the user to give the op a meaningful name
     fn add_optional_name(&mut self) {
         self.struct_.field("op_name", "Option<String>");
@@ -381,11 +431,15 @@ impl Builder {
     }
 
     /// Add all the generated code to the scope
-    fn finish(mut self, scope: &mut cg::Scope) -> Result<(), String> {
+    fn finish(mut self, scope: &mut cg::Scope, output_generics: &[String]) -> Result<(), String> {
         self.add_optional_name();
         self.add_control_inputs();
         scope.push_impl(self.graph_operation_impl()?);
         self.impl_.push_fn(self.make_finish()?);
+        let outputs = self.make_outputs(output_generics);
+        if let Some((_, ref outputs_fn)) = outputs {
+            self.impl_.push_fn(outputs_fn.clone());
+        }
 
         self.new_fn.ret("Self")
                    .vis("pub");
@@ -401,6 +455,9 @@ impl Builder {
         self.impl_.push_fn(self.direct_new_fn);
         scope.push_struct(self.struct_);
         scope.push_impl(self.impl_);
+        if let Some((outputs_struct, _)) = outputs {
+            scope.push_struct(outputs_struct);
+        }
 
         Ok(())
     }
@@ -418,10 +475,25 @@ impl OpImpl {
     }
 
     pub(crate) fn finish(self, lib: &mut OpLib) -> Result<(), String> {
-        self.builder.finish(&mut lib.scope)?;
+        let output_generics = self.output_generics()?;
+        self.builder.finish(&mut lib.scope, &output_generics)?;
         Ok(())
     }
 
+    /// The generics used by the output types, in order of appearance
+    fn output_generics(&self) -> Result<Vec<String>, String> {
+        let mut output_generics: Vec<String> = Vec::new();
+        for (ty, _, _) in &self.builder.outputs {
+            let ty = type_to_string(ty)?;
+            for word in ty.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+                if self.generics.contains(word) && !output_generics.iter().any(|g| g == word) {
+                    output_generics.push(word.to_string());
+                }
+            }
+        }
+        Ok(output_generics)
+    }
+
     pub(crate) fn generic(&mut self, ty: &str) {
         if self.generics.contains(ty) {
             return;
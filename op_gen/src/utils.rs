@@ -153,6 +153,7 @@ pub(crate) fn tf_data_type_to_rust(data_type: DataType) -> Result<String, String
         DataType::DT_COMPLEX128 => Ok("OtherComplex<f64>".to_string()),
         DataType::DT_UINT32 => Ok("u32".to_string()),
         DataType::DT_UINT64 => Ok("u64".to_string()),
+        DataType::DT_RESOURCE => Ok("ResourceHandle".to_string()),
         _ => Err(format!("Unsupported data type {:?}", data_type))
     }
 }
@@ -174,9 +175,7 @@ pub(crate) fn type_to_string(ty: &cg::Type) -> Result<String, String> {
 }
 
 pub(crate) fn dump_protobuf<T: protobuf::Message>(message: &T) -> Vec<u8>{
-    let mut buffer = Vec::new();
-    message.write_to_with_cached_sizes(&mut protobuf::CodedOutputStream::vec(&mut buffer)).expect("Failed to dump protobuf");
-    buffer
+    message.write_to_bytes().expect("Failed to dump protobuf")
 }
 
 pub(crate) fn join_vec<T>(v: &Vec<T>, delim: &str) -> String
@@ -0,0 +1,467 @@
+use super::DataType;
+use super::Edge;
+use super::Graph;
+use super::GraphEdge;
+use super::GraphOperation;
+use super::Operation;
+use super::Output;
+use super::Result;
+use super::TensorType;
+use super::gradients::add_gradients;
+use super::new_id;
+use std::collections::HashMap;
+use std::os::raw::c_int;
+use std::rc::Rc;
+
+/// Typed values passed into and out of control flow constructs: an `Edge`, a `Vec` of values
+/// of one type, or a tuple of values of any types, e.g. `(Edge<f32>, Vec<Edge<i32>>)`.
+pub trait Edges: Clone + 'static {
+    /// The number of edges
+    fn len(&self) -> usize;
+
+    /// Adds the edges to the graph, returning their outputs in order
+    fn outputs(&self, graph: &mut Graph) -> Result<Vec<Output>>;
+
+    /// Returns values of the same types and number, whose edges are taken in order from `edges`
+    fn with_edges(&self, edges: &mut dyn Iterator<Item = (Rc<dyn GraphOperation>, c_int)>) -> Self;
+}
+
+impl<T: TensorType> Edges for Edge<T> {
+    fn len(&self) -> usize {
+        1
+    }
+
+    fn outputs(&self, graph: &mut Graph) -> Result<Vec<Output>> {
+        Ok(vec![self.output(graph)?])
+    }
+
+    fn with_edges(&self, edges: &mut dyn Iterator<Item = (Rc<dyn GraphOperation>, c_int)>) -> Self {
+        let (parent, port) = edges.next().unwrap();
+        Edge::new(parent, port)
+    }
+}
+
+impl<E: Edges> Edges for Vec<E> {
+    fn len(&self) -> usize {
+        self.iter().map(Edges::len).sum()
+    }
+
+    fn outputs(&self, graph: &mut Graph) -> Result<Vec<Output>> {
+        let mut outputs = Vec::new();
+        for value in self {
+            outputs.extend(value.outputs(graph)?);
+        }
+        Ok(outputs)
+    }
+
+    fn with_edges(&self, edges: &mut dyn Iterator<Item = (Rc<dyn GraphOperation>, c_int)>) -> Self {
+        self.iter().map(|value| value.with_edges(edges)).collect()
+    }
+}
+
+macro_rules! impl_edges_for_tuple {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case)]
+        impl<$($name: Edges),*> Edges for ($($name,)*) {
+            fn len(&self) -> usize {
+                let ($(ref $name,)*) = *self;
+                0 $(+ $name.len())*
+            }
+
+            fn outputs(&self, graph: &mut Graph) -> Result<Vec<Output>> {
+                let ($(ref $name,)*) = *self;
+                let mut outputs = Vec::new();
+                $(outputs.extend($name.outputs(graph)?);)*
+                Ok(outputs)
+            }
+
+            fn with_edges(&self, edges: &mut dyn Iterator<Item = (Rc<dyn GraphOperation>, c_int)>) -> Self {
+                let ($(ref $name,)*) = *self;
+                ($($name.with_edges(edges),)*)
+            }
+        }
+    };
+}
+
+impl_edges_for_tuple!(A);
+impl_edges_for_tuple!(A, B);
+impl_edges_for_tuple!(A, B, C);
+impl_edges_for_tuple!(A, B, C, D);
+impl_edges_for_tuple!(A, B, C, D, E);
+impl_edges_for_tuple!(A, B, C, D, E, F);
+impl_edges_for_tuple!(A, B, C, D, E, F, G);
+impl_edges_for_tuple!(A, B, C, D, E, F, G, H);
+
+////////////////////////
+
+/// A branch of a conditional being added to a graph.
+///
+/// Typed ops added to the graph while it is active take their inputs from inside the branch,
+/// so edges from outside the branch are passed through a `Switch` on the predicate. Together
+/// with the `Switch` added for each output of the branch, this means the branch only runs when
+/// it is taken.
+///
+/// Typed ops created outside the branch functions are added outside the branch, even if they are
+/// first needed inside it, so their other uses don't depend on which branch is taken.
+#[derive(Debug)]
+pub(crate) struct CondContext {
+    // Ops with a lower id were created before the branch functions were called.
+    first_id: usize,
+    pred: Output,
+    branch: c_int,
+    // Whether each operation depends on one of the switches into the branch, by name.
+    in_branch: HashMap<String, bool>,
+    // The switched version of each output from outside the branch.
+    switches: HashMap<(String, c_int), Output>,
+}
+
+/// Returns an output which can be used as an input by an operation in the innermost conditional
+/// branch, if any, switching it into each enclosing branch it isn't in already.
+pub(crate) fn cond_input(graph: &mut Graph, mut output: Output) -> Result<Output> {
+    for level in 0..graph.cond_contexts().len() {
+        output = switch_into(graph, level, output)?;
+    }
+    Ok(output)
+}
+
+impl CondContext {
+    // Whether the op with the id was created outside the branch functions, i.e. before they were
+    // called.
+    pub(crate) fn created_outside(&self, id: usize) -> bool {
+        id < self.first_id
+    }
+}
+
+fn switch_into(graph: &mut Graph, level: usize, output: Output) -> Result<Output> {
+    let key = (output.operation.name()?, output.index);
+    let context = &mut graph.cond_contexts()[level];
+    if let Some(switched) = context.switches.get(&key) {
+        return Ok(switched.clone());
+    }
+    if in_branch(context, &output.operation)? {
+        return Ok(output);
+    }
+    let pred = context.pred.clone();
+    let branch = context.branch;
+    let op_type = if is_ref(output.operation.output_type(output.index as usize)) {
+        "RefSwitch"
+    } else {
+        "Switch"
+    };
+    let name = graph.new_op_name(&graph.scoped_name(&format!("{}_{{}}", op_type)))?;
+    let mut switch = graph.new_operation(op_type, &name)?;
+    switch.add_input(output);
+    switch.add_input(pred);
+    let switch = switch.finish()?;
+    let context = &mut graph.cond_contexts()[level];
+    context.in_branch.insert(switch.name()?, true);
+    let switched = Output { operation: switch, index: branch };
+    context.switches.insert(key, switched.clone());
+    Ok(switched)
+}
+
+fn is_ref(data_type: DataType) -> bool {
+    data_type.to_int() > 100
+}
+
+// Whether op depends on one of the switches into the branch.
+fn in_branch(context: &mut CondContext, op: &Operation) -> Result<bool> {
+    let mut stack = vec![(op.clone(), false)];
+    while let Some((op, expanded)) = stack.pop() {
+        let name = op.name()?;
+        if expanded {
+            let mut found = false;
+            for i in 0..op.num_inputs() {
+                found |= context.in_branch[&op.input(i).0.name()?];
+            }
+            context.in_branch.insert(name, found);
+        } else if !context.in_branch.contains_key(&name) {
+            // Operations on a cycle, i.e. in a while loop, are treated as outside the branch
+            // until they have been finished.
+            context.in_branch.insert(name, false);
+            stack.push((op.clone(), true));
+            for i in 0..op.num_inputs() {
+                stack.push((op.input(i).0, false));
+            }
+        }
+    }
+    Ok(context.in_branch[&op.name()?])
+}
+
+/// Returns the outputs of `true_fn` if `pred` is true, and those of `false_fn` otherwise.
+///
+/// Both functions must return values of the same types, and only the taken branch is run. Edges
+/// created outside the functions can be used inside them, and gradients flow through the
+/// conditional into them. The operations of each branch are added to the graph along with the
+/// conditional, which is named `cond_N`, with the operations of each branch under `cond_N/true`
+/// and `cond_N/false`.
+pub fn cond<O, F, G>(pred: Edge<bool>, true_fn: F, false_fn: G) -> O
+where O: Edges,
+      F: FnOnce() -> O,
+      G: FnOnce() -> O, {
+    let first_id = new_id();
+    let true_outputs = true_fn();
+    let false_outputs = false_fn();
+    let cond = Rc::new(CondOp {
+        first_id,
+        pred,
+        ids: (0..true_outputs.len()).map(|_| new_id()).collect(),
+        branches: [false_outputs, true_outputs.clone()],
+    });
+    let mut edges = (0..cond.ids.len()).map(|index| {
+        let output: Rc<dyn GraphOperation> = Rc::new(CondOutput { cond: cond.clone(), index });
+        (output, 0)
+    });
+    true_outputs.with_edges(&mut edges)
+}
+
+// Adds a conditional to the graph, recording the `Merge` of each output.
+struct CondOp<O: Edges> {
+    first_id: usize,
+    pred: Edge<bool>,
+    // The outputs of the false and true branches, in that order.
+    branches: [O; 2],
+    ids: Vec<usize>,
+}
+
+impl<O: Edges> CondOp<O> {
+    fn add_to_graph(&self, graph: &mut Graph) -> Result<()> {
+        if self.ids.is_empty() || graph.get_op_by_id(self.ids[0]).is_some() {
+            return Ok(());
+        }
+        graph.outside_cond_contexts(self.first_id, |graph| self.add_branches(graph))
+    }
+
+    fn add_branches(&self, graph: &mut Graph) -> Result<()> {
+        let pred = self.pred.output(graph)?;
+        let pred = cond_input(graph, pred)?;
+        let pred_id_name = graph.new_op_name(&graph.scoped_name("cond_{}/pred_id"))?;
+        let scope = pred_id_name.trim_end_matches("/pred_id").to_string();
+        let mut pred_id = graph.new_operation("Identity", &pred_id_name)?;
+        pred_id.add_input(pred);
+        let pred = Output { operation: pred_id.finish()?, index: 0 };
+
+        let mut branch_outputs = Vec::new();
+        let mut switches = Vec::new();
+        for (branch, name) in BRANCH_NAMES.iter().enumerate() {
+            graph.cond_contexts().push(CondContext {
+                first_id: self.first_id,
+                pred: pred.clone(),
+                branch: branch as c_int,
+                in_branch: HashMap::new(),
+                switches: HashMap::new(),
+            });
+            let outputs = graph.with_scope(&format!("{}/{}/", scope, name), |graph| -> Result<Vec<Output>> {
+                let mut outputs = Vec::new();
+                for output in self.branches[branch].outputs(graph)? {
+                    outputs.push(cond_input(graph, output)?);
+                }
+                Ok(outputs)
+            });
+            let context = graph.cond_contexts().pop().unwrap();
+            branch_outputs.push(outputs?);
+            switches.push(context.switches);
+        }
+        if branch_outputs[0].len() != branch_outputs[1].len() {
+            return Err(invalid_arg!("The true branch of {} has {} outputs but the false branch has {}",
+                                    scope, branch_outputs[1].len(), branch_outputs[0].len()));
+        }
+
+        let mut merges = Vec::new();
+        for (i, id) in self.ids.iter().enumerate() {
+            let mut merge = graph.new_operation("Merge", &graph.new_op_name(&format!("{}/Merge_{{}}", scope))?)?;
+            merge.add_input_list(&[branch_outputs[0][i].clone(), branch_outputs[1][i].clone()]);
+            let merge = merge.finish()?;
+            merges.push(Output { operation: merge.clone(), index: 0 });
+            graph.record_op(*id, merge);
+        }
+
+        // The gradient is registered for this conditional only, since while loops are built from
+        // the same operations.
+        let mut keys: Vec<_> = switches[0].keys().chain(switches[1].keys()).cloned().collect();
+        keys.sort();
+        keys.dedup();
+        let mut inputs = vec![pred];
+        let mut switched = [Vec::new(), Vec::new()];
+        for key in &keys {
+            let switch = switches[0].get(key).or_else(|| switches[1].get(key)).unwrap();
+            let (operation, index) = switch.operation.input(0);
+            inputs.push(Output { operation, index: index as c_int });
+            for (switched, switches) in switched.iter_mut().zip(&switches) {
+                switched.push(switches.get(key).cloned());
+            }
+        }
+        let gradient = CondGradient {
+            scope,
+            inputs: inputs.clone(),
+            switched,
+            branch_outputs,
+        };
+        graph.register_subgraph_gradient(&inputs, &merges, move |graph, dys| gradient.add(graph, dys));
+        Ok(())
+    }
+}
+
+struct CondOutput<O: Edges> {
+    cond: Rc<CondOp<O>>,
+    index: usize,
+}
+
+impl<O: Edges> GraphOperation for CondOutput<O> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.cond.add_to_graph(graph)?;
+        Ok(graph.get_op_by_id(self.cond.ids[self.index]).unwrap())
+    }
+}
+
+////////////////////////
+
+const BRANCH_NAMES: [&str; 2] = ["false", "true"];
+
+// The gradient of a conditional, which flows back through whichever branch was taken.
+struct CondGradient {
+    scope: String,
+    // The predicate, followed by each output from outside the conditional used by a branch.
+    inputs: Vec<Output>,
+    // The switched version of each output from outside the conditional in each branch, if the
+    // branch uses it.
+    switched: [Vec<Option<Output>>; 2],
+    // The outputs of the false and true branches, in that order.
+    branch_outputs: Vec<Vec<Output>>,
+}
+
+impl CondGradient {
+    fn add(&self, graph: &mut Graph, dys: &[Option<Output>]) -> Result<Vec<Option<Output>>> {
+        let pred = &self.inputs[0];
+        let mut grads = vec![[None, None]; self.inputs.len() - 1];
+        for (branch, name) in BRANCH_NAMES.iter().enumerate() {
+            let mut ys = Vec::new();
+            let mut dxs = Vec::new();
+            for (y, dy) in self.branch_outputs[branch].iter().zip(dys) {
+                if let Some(dy) = dy {
+                    ys.push(y.clone());
+                    dxs.push(self.switch(graph, dy, pred, branch)?);
+                }
+            }
+            let (indices, xs): (Vec<usize>, Vec<Output>) = self.switched[branch].iter()
+                .enumerate()
+                .filter_map(|(i, x)| x.clone().map(|x| (i, x)))
+                .unzip();
+            if ys.is_empty() || xs.is_empty() {
+                continue;
+            }
+            let prefix = graph.new_op_name(&format!("{}_grad/{}_{{}}", self.scope, name))?;
+            let branch_grads = add_gradients(graph, Some(&prefix), &ys, &xs, Some(&dxs))?;
+            for (i, grad) in indices.into_iter().zip(branch_grads) {
+                grads[i][branch] = grad;
+            }
+        }
+
+        // Zeros stand in for the gradient from a branch which doesn't use an input, so that the
+        // Merge has a live input whichever branch is taken.
+        let mut input_grads = vec![None];
+        for (input, grads) in self.inputs[1..].iter().zip(grads) {
+            if grads.iter().all(Option::is_none) {
+                input_grads.push(None);
+                continue;
+            }
+            let mut branch_grads = Vec::new();
+            for (branch, grad) in grads.iter().enumerate() {
+                branch_grads.push(match grad {
+                    Some(grad) => grad.clone(),
+                    None => {
+                        let switched = self.switch(graph, input, pred, branch)?;
+                        let name = graph.new_op_name(&format!("{}_grad/ZerosLike_{{}}", self.scope))?;
+                        let mut zeros = graph.new_operation("ZerosLike", &name)?;
+                        zeros.add_input(switched);
+                        Output { operation: zeros.finish()?, index: 0 }
+                    }
+                });
+            }
+            let mut merge = graph.new_operation("Merge", &graph.new_op_name(&format!("{}_grad/Merge_{{}}", self.scope))?)?;
+            merge.add_input_list(&branch_grads);
+            input_grads.push(Some(Output { operation: merge.finish()?, index: 0 }));
+        }
+        Ok(input_grads)
+    }
+
+    // Returns the output of a Switch forwarding output into branch.
+    fn switch(&self, graph: &mut Graph, output: &Output, pred: &Output, branch: usize) -> Result<Output> {
+        let mut switch = graph.new_operation("Switch", &graph.new_op_name(&format!("{}_grad/Switch_{{}}", self.scope))?)?;
+        switch.add_input(output.clone());
+        switch.add_input(pred.clone());
+        Ok(Output { operation: switch.finish()?, index: branch as c_int })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::sugar::{constant, Gradients};
+    use super::super::{ops, Session, SessionOptions, TypedWhileBuilder};
+
+    fn run(pred: bool) -> (f32, i32, f32) {
+        let mut graph = Graph::new();
+        let x = constant(3.0f32);
+        let n = constant(5);
+        let (y, m) = cond(constant(pred),
+                          || (x.clone() * x.clone(), n.clone() + constant(1)),
+                          || (x.clone() * constant(4.0f32), constant(0)));
+        let grad = Gradients::new(None, vec![y.clone()], vec![x.clone()]).edges(&mut graph).unwrap();
+        let grad = grad[0].clone().unwrap();
+        let sess = Session::new(&SessionOptions::new(), &graph).unwrap();
+        (sess.fetch(&mut graph, &y).unwrap()[0],
+         sess.fetch(&mut graph, &m).unwrap()[0],
+         sess.fetch(&mut graph, &grad).unwrap()[0])
+    }
+
+    #[test]
+    fn test_cond() {
+        assert_eq!(run(true), (9.0, 6, 6.0));
+        assert_eq!(run(false), (12.0, 0, 4.0));
+    }
+
+    #[test]
+    fn test_captured_edge() {
+        let mut graph = Graph::new();
+        let x = constant(2.0f32) + constant(1.0f32);
+        let y = cond(constant(false), || x.clone() * constant(2.0f32), || x.clone() * constant(3.0f32));
+        let z = x.clone() + constant(1.0f32);
+        // x is first needed inside a branch, but is still added outside the conditional.
+        y.operation(&mut graph).unwrap();
+        let sess = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(sess.fetch(&mut graph, &y).unwrap()[0], 9.0);
+        assert_eq!(sess.fetch(&mut graph, &x).unwrap()[0], 3.0);
+        assert_eq!(sess.fetch(&mut graph, &z).unwrap()[0], 4.0);
+    }
+
+    #[test]
+    fn test_nested_cond() {
+        let mut graph = Graph::new();
+        let x = constant(vec![1, 2]);
+        let y = cond(constant(true),
+                     || cond(constant(false), || x.clone(), || ops::Neg::new(x.clone())),
+                     || x.clone());
+        let sess = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(sess.fetch(&mut graph, &y).unwrap(), vec![-1, -2].into());
+    }
+
+    #[test]
+    fn test_cond_and_while_gradients() {
+        let mut graph = Graph::new();
+        let x = constant(3.0f32);
+        let y = cond(constant(true), || x.clone() * x.clone(), || x.clone() * constant(4.0f32));
+        // Doubles x three times.
+        let (_, z) = TypedWhileBuilder::new((constant(0i32), x.clone()),
+                                           |(i, _)| ops::Less::new(i, constant(3i32)),
+                                           |(i, z)| (i + constant(1i32), z * constant(2.0f32))).finish();
+        let grad = Gradients::new(None, vec![y + z.clone()], vec![x.clone()]).edges(&mut graph).unwrap();
+        let grad = grad[0].clone().unwrap();
+        // The gradient of the cond must not be used for the loop's own Switch and Merge.
+        let loop_grad = Gradients::new(None, vec![z], vec![x]).edges(&mut graph).unwrap();
+        let loop_grad = loop_grad[0].clone().unwrap();
+        let sess = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(sess.fetch(&mut graph, &grad).unwrap()[0], 14.0);
+        assert_eq!(sess.fetch(&mut graph, &loop_grad).unwrap()[0], 8.0);
+    }
+}
@@ -0,0 +1,250 @@
+//! Typed TensorFlow functions, defined from Rust closures and called from other graphs.
+//!
+//! `define_function` builds a function from its body, and the returned `DefinedFunction` adds
+//! typed calls to it, copying the function into the graph of each call when it is added.
+
+use super::AnyEdge;
+use super::DataType;
+use super::Edge;
+use super::Function;
+use super::FunctionOptions;
+use super::Graph;
+use super::GraphOperation;
+use super::Operation;
+use super::Output;
+use super::Result;
+use super::TensorType;
+use super::ops;
+use std::fmt;
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+use std::rc::Rc;
+
+/// Typed values which can be the arguments or results of a function defined with
+/// `define_function`: an `Edge`, or a tuple of values of any such types, e.g.
+/// `(Edge<f32>, Edge<i32>)`.
+pub trait FunctionValues: Sized + 'static {
+    /// The type of each edge, in order
+    fn data_types() -> Vec<DataType>;
+
+    /// Converts the values into untyped edges, in order
+    fn into_any_edges(self) -> Vec<AnyEdge>;
+
+    /// Converts untyped edges, taken in order from `edges`, into values.
+    ///
+    /// Returns an error if an edge has the wrong type.
+    fn from_any_edges(edges: &mut dyn Iterator<Item = AnyEdge>) -> Result<Self>;
+}
+
+impl<T: TensorType> FunctionValues for Edge<T> {
+    fn data_types() -> Vec<DataType> {
+        vec![T::data_type()]
+    }
+
+    fn into_any_edges(self) -> Vec<AnyEdge> {
+        vec![self.into()]
+    }
+
+    fn from_any_edges(edges: &mut dyn Iterator<Item = AnyEdge>) -> Result<Self> {
+        match edges.next() {
+            Some(edge) => edge.typed(),
+            None => Err(invalid_arg!("Expected an edge of type {}, but there are no edges left",
+                                     T::data_type())),
+        }
+    }
+}
+
+macro_rules! impl_function_values_for_tuple {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case)]
+        impl<$($name: FunctionValues),*> FunctionValues for ($($name,)*) {
+            fn data_types() -> Vec<DataType> {
+                let mut data_types = Vec::new();
+                $(data_types.extend($name::data_types());)*
+                data_types
+            }
+
+            fn into_any_edges(self) -> Vec<AnyEdge> {
+                let ($($name,)*) = self;
+                let mut edges = Vec::new();
+                $(edges.extend($name.into_any_edges());)*
+                edges
+            }
+
+            fn from_any_edges(edges: &mut dyn Iterator<Item = AnyEdge>) -> Result<Self> {
+                Ok(($($name::from_any_edges(edges)?,)*))
+            }
+        }
+    };
+}
+
+impl_function_values_for_tuple!(A);
+impl_function_values_for_tuple!(A, B);
+impl_function_values_for_tuple!(A, B, C);
+impl_function_values_for_tuple!(A, B, C, D);
+impl_function_values_for_tuple!(A, B, C, D, E);
+impl_function_values_for_tuple!(A, B, C, D, E, F);
+impl_function_values_for_tuple!(A, B, C, D, E, F, G);
+impl_function_values_for_tuple!(A, B, C, D, E, F, G, H);
+
+////////////////////////
+
+/// Defines a TensorFlow function called `name`, whose body is built by `body` in a graph of its
+/// own, e.g.
+///
+/// ```ignore
+/// let affine = define_function("affine", |_, (x, m, c): (Edge<f32>, Edge<f32>, Edge<f32>)| {
+///     Ok(x * m + c)
+/// })?;
+/// let y = affine.call((x, m, c));
+/// ```
+///
+/// `body` is given the function's arguments, and returns its results. Any operations it needs
+/// are added to the function's graph, so it can't use edges from other graphs.
+pub fn define_function<I, O, F>(name: &str, body: F) -> Result<DefinedFunction<I, O>>
+where I: FunctionValues,
+      O: FunctionValues,
+      F: FnOnce(&mut Graph, I) -> Result<O>, {
+    let mut graph = Graph::new();
+    let mut inputs = Vec::new();
+    let mut args = Vec::new();
+    for (i, data_type) in I::data_types().into_iter().enumerate() {
+        let mut placeholder = graph.new_operation("Placeholder", &format!("input_{}", i))?;
+        placeholder.set_attr_type("dtype", data_type)?;
+        let placeholder = placeholder.finish()?;
+        inputs.push(Output { operation: placeholder.clone(), index: 0 });
+        args.push(AnyEdge::new(Rc::new(placeholder), 0, data_type));
+    }
+    let results = body(&mut graph, I::from_any_edges(&mut args.into_iter())?)?;
+    let mut outputs = Vec::new();
+    for edge in results.into_any_edges() {
+        outputs.push(edge.output(&mut graph)?);
+    }
+    let output_names: Vec<_> = (0..outputs.len()).map(|i| format!("output_{}", i)).collect();
+    let function = graph.to_function(name,
+                                     false,
+                                     None,
+                                     &inputs,
+                                     &outputs,
+                                     Some(&output_names),
+                                     &FunctionOptions::new(),
+                                     None)?;
+    Ok(DefinedFunction {
+        name: name.to_string(),
+        function: Rc::new(function),
+        phantom: PhantomData,
+    })
+}
+
+/// A TensorFlow function defined by `define_function`, taking arguments of type `I` and
+/// returning results of type `O`.
+///
+/// Calling the function adds it to the graph the call is added to, if it isn't there already.
+pub struct DefinedFunction<I: FunctionValues, O: FunctionValues> {
+    name: String,
+    function: Rc<Function>,
+    phantom: PhantomData<(I, O)>,
+}
+
+impl<I: FunctionValues, O: FunctionValues> Clone for DefinedFunction<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            function: self.function.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: FunctionValues, O: FunctionValues> fmt::Debug for DefinedFunction<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DefinedFunction")
+         .field("name", &self.name)
+         .finish()
+    }
+}
+
+impl<I: FunctionValues, O: FunctionValues> DefinedFunction<I, O> {
+    /// The name of the function
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The function itself, e.g. to copy it into a graph with `Graph::copy_function`
+    pub fn function(&self) -> &Function {
+        &self.function
+    }
+
+    /// Calls the function with a `PartitionedCall`, which must only be used if the function
+    /// has no stateful operations.
+    pub fn call(&self, args: I) -> O {
+        let call = ops::PartitionedCall::build(&args.into_any_edges(), &O::data_types(), &self.name);
+        self.results(call)
+    }
+
+    /// Calls the function with a `StatefulPartitionedCall`, which may be used whether or not
+    /// the function has stateful operations.
+    pub fn call_stateful(&self, args: I) -> O {
+        let call = ops::StatefulPartitionedCall::build(&args.into_any_edges(), &O::data_types(), &self.name);
+        self.results(call)
+    }
+
+    fn results<C: GraphOperation + 'static>(&self, call: C) -> O {
+        let call = Rc::new(FunctionCall {
+            function: self.function.clone(),
+            call,
+        });
+        let mut edges = O::data_types().into_iter().enumerate().map(|(i, data_type)| {
+            let parent: Rc<dyn GraphOperation> = call.clone();
+            AnyEdge::new(parent, i as c_int, data_type)
+        });
+        // The edges have the types given by O, so they always convert.
+        O::from_any_edges(&mut edges).unwrap()
+    }
+}
+
+// Copies the function into the graph before adding the operation calling it.
+struct FunctionCall<C: GraphOperation> {
+    function: Rc<Function>,
+    call: C,
+}
+
+impl<C: GraphOperation> GraphOperation for FunctionCall<C> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        graph.copy_function(&self.function, None)?;
+        self.call.tf_operation(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Session, SessionOptions};
+    use super::super::sugar::constant;
+
+    #[test]
+    fn test_define_function() {
+        let affine = define_function("affine", |_, (x, m, c): (Edge<f32>, Edge<f32>, Edge<f32>)| {
+            Ok(x * m + c)
+        }).unwrap();
+        assert_eq!(affine.name(), "affine");
+        let mut graph = Graph::new();
+        let y = affine.call((constant(2.0f32), constant(3.0f32), constant(1.0f32)));
+        let z = affine.call_stateful((y.clone(), constant(2.0f32), constant(-4.0f32)));
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(session.fetch(&mut graph, &y).unwrap()[0], 7.0);
+        assert_eq!(session.fetch(&mut graph, &z).unwrap()[0], 10.0);
+    }
+
+    #[test]
+    fn test_mixed_types() {
+        let step = define_function("step", |_, (i, x): (Edge<i32>, Edge<f32>)| {
+            Ok((i + constant(1i32), x * constant(0.5f32)))
+        }).unwrap();
+        let mut graph = Graph::new();
+        let (i, x) = step.call((constant(4i32), constant(3.0f32)));
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(session.fetch(&mut graph, &i).unwrap()[0], 5);
+        assert_eq!(session.fetch(&mut graph, &x).unwrap()[0], 1.5);
+    }
+}
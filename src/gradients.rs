@@ -0,0 +1,455 @@
+use super::Graph;
+use super::Operation;
+use super::Output;
+use super::Result;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use std::os::raw::c_int;
+use std::sync::Arc;
+
+/// Computes the gradients flowing into the inputs of an operation from the gradients flowing
+/// into its outputs.
+///
+/// It is called with the graph, the operation, and one gradient per output of the operation,
+/// which is None if no gradient flows into that output. It must return one gradient per input of
+/// the operation, None meaning that no gradient flows into that input.
+pub type GradientFunction =
+    dyn Fn(&mut Graph, &Operation, &[Option<Output>]) -> Result<Vec<Option<Output>>> + Send + Sync;
+
+/// Computes the gradients flowing into the inputs of a subgraph from the gradients flowing into
+/// its outputs. See `Graph::register_subgraph_gradient`.
+pub type SubgraphGradientFunction =
+    dyn Fn(&mut Graph, &[Option<Output>]) -> Result<Vec<Option<Output>>> + Send + Sync;
+
+#[derive(Clone)]
+struct SubgraphGradient {
+    inputs: Vec<Output>,
+    outputs: Vec<Output>,
+    function: Arc<SubgraphGradientFunction>,
+}
+
+/// The gradient functions registered with a graph, which take precedence over the gradients
+/// registered in C++.
+#[derive(Clone, Default)]
+pub(crate) struct GradientRegistry {
+    op_types: HashMap<String, Arc<GradientFunction>>,
+    subgraphs: Vec<SubgraphGradient>,
+}
+
+impl fmt::Debug for GradientRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GradientRegistry")
+            .field("op_types", &self.op_types.keys().collect::<Vec<_>>())
+            .field("subgraphs", &self.subgraphs.len())
+            .finish()
+    }
+}
+
+impl GradientRegistry {
+    pub(crate) fn register(&mut self, op_type: &str, function: Arc<GradientFunction>) {
+        self.op_types.insert(op_type.to_string(), function);
+    }
+
+    pub(crate) fn register_subgraph(&mut self,
+                                    inputs: &[Output],
+                                    outputs: &[Output],
+                                    function: Arc<SubgraphGradientFunction>) {
+        self.subgraphs.push(SubgraphGradient {
+            inputs: inputs.to_vec(),
+            outputs: outputs.to_vec(),
+            function,
+        });
+    }
+}
+
+// Identifies an output by operation name and index, since Operations can't be compared.
+type Key = (String, c_int);
+
+fn key(output: &Output) -> Result<Key> {
+    Ok((output.operation.name()?, output.index))
+}
+
+/// Adds operations to compute the partial derivatives of the sum of `y`s w.r.t. `x`s, like
+/// `Graph::add_gradients`, but using the gradient functions registered with the graph where they
+/// apply. The gradients of other operations are added by the C API, one operation at a time.
+pub(crate) fn add_gradients(graph: &mut Graph,
+                            prefix: Option<&str>,
+                            y: &[Output],
+                            x: &[Output],
+                            dx: Option<&[Output]>) -> Result<Vec<Option<Output>>> {
+    if let Some(dx) = dx {
+        if dx.len() != y.len() {
+            return Err(invalid_arg!("dx.len() must equal y.len() ({} vs. {})", dx.len(), y.len()));
+        }
+    }
+    let registry = graph.gradient_registry().clone();
+    let sources = x.iter().map(|x| x.operation.name()).collect::<std::result::Result<HashSet<_>, _>>()?;
+    let ops = ops_between(y, &sources)?;
+    let names = ops.iter().map(Operation::name).collect::<std::result::Result<HashSet<_>, _>>()?;
+
+    let mut subgraphs = Vec::new();
+    for subgraph in &registry.subgraphs {
+        let mut applies = false;
+        for output in &subgraph.outputs {
+            applies |= names.contains(&output.operation.name()?);
+        }
+        if applies {
+            subgraphs.push(subgraph.clone());
+        }
+    }
+    let mut overridden = !subgraphs.is_empty();
+    for op in &ops {
+        overridden |= registry.op_types.contains_key(&op.op_type()?);
+    }
+    if !overridden {
+        return graph.add_gradients(prefix, y, x, dx);
+    }
+
+    let base = prefix.unwrap_or("gradients");
+    let mut internal = HashSet::new();
+    for subgraph in &subgraphs {
+        internal.extend(subgraph_ops(subgraph)?);
+    }
+    for subgraph in while_loops(graph, base, &names, &internal)? {
+        internal.extend(subgraph_ops(&subgraph)?);
+        subgraphs.push(subgraph);
+    }
+
+    // Each subgraph is differentiated when the backward pass reaches the output computed
+    // earliest, and the operations inside it are skipped.
+    let mut subgraphs_at: HashMap<String, Vec<SubgraphGradient>> = HashMap::new();
+    for subgraph in subgraphs {
+        let mut first = None;
+        for op in &ops {
+            let name = op.name()?;
+            if subgraph.outputs.iter().any(|output| output.operation.name().ok() == Some(name.clone())) {
+                first = Some(name);
+                break;
+            }
+        }
+        if let Some(name) = first {
+            subgraphs_at.entry(name).or_insert_with(Vec::new).push(subgraph);
+        }
+    }
+
+    let mut grads: HashMap<Key, Vec<Output>> = HashMap::new();
+    for (i, output) in y.iter().enumerate() {
+        let grad = match dx {
+            Some(dx) => dx[i].clone(),
+            None => {
+                let mut ones = graph.new_operation("OnesLike", &graph.new_op_name(&format!("{}/OnesLike_{{}}", base))?)?;
+                ones.add_input(output.clone());
+                Output { operation: ones.finish()?, index: 0 }
+            }
+        };
+        grads.entry(key(output)?).or_insert_with(Vec::new).push(grad);
+    }
+
+    for op in ops.iter().rev() {
+        let name = op.name()?;
+        for subgraph in subgraphs_at.get(&name).into_iter().flatten() {
+            let mut dys = Vec::new();
+            for output in &subgraph.outputs {
+                dys.push(total_gradient(graph, base, &mut grads, output)?);
+            }
+            if dys.iter().all(Option::is_none) {
+                continue;
+            }
+            let dxs = (subgraph.function)(graph, &dys)?;
+            if dxs.len() != subgraph.inputs.len() {
+                return Err(invalid_arg!("Subgraph gradient returned {} gradients for {} inputs",
+                                        dxs.len(), subgraph.inputs.len()));
+            }
+            for (input, dx) in subgraph.inputs.iter().zip(dxs) {
+                if let Some(dx) = dx {
+                    grads.entry(key(input)?).or_insert_with(Vec::new).push(dx);
+                }
+            }
+        }
+        if internal.contains(&name) {
+            continue;
+        }
+
+        let mut dys = Vec::new();
+        for index in 0..op.num_outputs() {
+            let output = Output { operation: op.clone(), index: index as c_int };
+            dys.push(total_gradient(graph, base, &mut grads, &output)?);
+        }
+        let inputs: Vec<Output> = (0..op.num_inputs())
+            .map(|i| {
+                let (operation, index) = op.input(i);
+                Output { operation, index: index as c_int }
+            })
+            .collect();
+        let mut differentiable = vec![false; inputs.len()];
+        for (i, input) in inputs.iter().enumerate() {
+            differentiable[i] = names.contains(&input.operation.name()?);
+        }
+        if dys.iter().all(Option::is_none) || !differentiable.contains(&true) {
+            continue;
+        }
+
+        let dxs = match registry.op_types.get(&op.op_type()?) {
+            Some(function) => {
+                let dxs = function(graph, op, &dys)?;
+                if dxs.len() != inputs.len() {
+                    return Err(invalid_arg!("Gradient of {} returned {} gradients for {} inputs",
+                                            name, dxs.len(), inputs.len()));
+                }
+                dxs
+            }
+            None => {
+                let (ys, dys): (Vec<Output>, Vec<Output>) = dys.into_iter()
+                    .enumerate()
+                    .filter_map(|(index, dy)| dy.map(|dy| (Output { operation: op.clone(), index: index as c_int }, dy)))
+                    .unzip();
+                let xs: Vec<Output> = inputs.iter()
+                    .zip(&differentiable)
+                    .filter(|(_, differentiable)| **differentiable)
+                    .map(|(input, _)| input.clone())
+                    .collect();
+                let op_prefix = graph.new_op_name(&format!("{}/{}_grad_{{}}", base, name))?;
+                let mut dxs = graph.add_gradients(Some(&op_prefix), &ys, &xs, Some(&dys))?.into_iter();
+                differentiable.iter().map(|d| if *d { dxs.next().unwrap() } else { None }).collect()
+            }
+        };
+        for ((input, dx), differentiable) in inputs.iter().zip(dxs).zip(&differentiable) {
+            if let (Some(dx), true) = (dx, differentiable) {
+                grads.entry(key(input)?).or_insert_with(Vec::new).push(dx);
+            }
+        }
+    }
+
+    x.iter().map(|x| total_gradient(graph, base, &mut grads, x)).collect()
+}
+
+// Returns the operations which y depend on and which are, or depend on, an operation in sources,
+// in topological order.
+fn ops_between(y: &[Output], sources: &HashSet<String>) -> Result<Vec<Operation>> {
+    let mut reaches: HashMap<String, bool> = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack: Vec<(Operation, bool)> = y.iter().map(|y| (y.operation.clone(), false)).collect();
+    while let Some((op, expanded)) = stack.pop() {
+        let name = op.name()?;
+        if expanded {
+            let mut reached = sources.contains(&name);
+            for i in 0..op.num_inputs() {
+                // Inputs which haven't been finished yet are on a cycle, i.e. in a while loop.
+                reached |= reaches.get(&op.input(i).0.name()?).cloned().unwrap_or(false);
+            }
+            reaches.insert(name, reached);
+            if reached {
+                order.push(op);
+            }
+        } else if visited.insert(name) {
+            stack.push((op.clone(), true));
+            for i in 0..op.num_inputs() {
+                let (input, _) = op.input(i);
+                if !visited.contains(&input.name()?) {
+                    stack.push((input, false));
+                }
+            }
+        }
+    }
+    Ok(order)
+}
+
+// Returns a subgraph for each while loop with an exit in ops, other than those inside internal,
+// whose gradient is added by the C API for the whole loop at once. The C API can't differentiate
+// the operations of a loop one at a time.
+fn while_loops(graph: &Graph,
+               base: &str,
+               ops: &HashSet<String>,
+               internal: &HashSet<String>) -> Result<Vec<SubgraphGradient>> {
+    let mut frames: Vec<(String, Vec<Output>)> = Vec::new();
+    for op in graph.operation_iter() {
+        let op_type = op.op_type()?;
+        if op_type != "Exit" && op_type != "RefExit" {
+            continue;
+        }
+        let frame = frame_name(&op)?;
+        let exit = Output { operation: op, index: 0 };
+        match frames.iter_mut().find(|(name, _)| *name == frame) {
+            Some((_, exits)) => exits.push(exit),
+            None => frames.push((frame, vec![exit])),
+        }
+    }
+
+    let mut loops = Vec::new();
+    for (frame, exits) in frames {
+        let mut reached = false;
+        for exit in &exits {
+            let name = exit.operation.name()?;
+            reached |= ops.contains(&name) && !internal.contains(&name);
+        }
+        if !reached {
+            continue;
+        }
+        let inputs = loop_inputs(&frame, &exits)?;
+        let mut differentiable = Vec::new();
+        for input in &inputs {
+            differentiable.push(ops.contains(&input.operation.name()?));
+        }
+        let base = base.to_string();
+        let function_exits = exits.clone();
+        let function_inputs = inputs.clone();
+        let function = move |graph: &mut Graph, dys: &[Option<Output>]| -> Result<Vec<Option<Output>>> {
+            // The C API expects a gradient for every exit of the loop.
+            let mut dxs = Vec::new();
+            for (exit, dy) in function_exits.iter().zip(dys) {
+                dxs.push(match dy {
+                    Some(dy) => dy.clone(),
+                    None => {
+                        let name = graph.new_op_name(&format!("{}/ZerosLike_{{}}", base))?;
+                        let mut zeros = graph.new_operation("ZerosLike", &name)?;
+                        zeros.add_input(exit.clone());
+                        Output { operation: zeros.finish()?, index: 0 }
+                    }
+                });
+            }
+            let xs: Vec<Output> = function_inputs.iter()
+                .zip(&differentiable)
+                .filter(|(_, differentiable)| **differentiable)
+                .map(|(input, _)| input.clone())
+                .collect();
+            let loop_prefix = graph.new_op_name(&format!("{}/{}_grad_{{}}", base, frame))?;
+            let mut grads = graph.add_gradients(Some(&loop_prefix), &function_exits, &xs, Some(&dxs))?.into_iter();
+            Ok(differentiable.iter().map(|d| if *d { grads.next().unwrap() } else { None }).collect())
+        };
+        loops.push(SubgraphGradient {
+            inputs,
+            outputs: exits,
+            function: Arc::new(function),
+        });
+    }
+    Ok(loops)
+}
+
+// Returns the frame of the while loop an Exit belongs to, which is an attribute of the Enter
+// its value comes from through a Switch and a Merge.
+fn frame_name(exit: &Operation) -> Result<String> {
+    let mut op = exit.clone();
+    loop {
+        let op_type = op.op_type()?;
+        if op_type == "Enter" || op_type == "RefEnter" {
+            return op.get_attr_string("frame_name");
+        }
+        if op.num_inputs() == 0 {
+            return Err(invalid_arg!("No Enter found for the Exit {}", exit.name()?));
+        }
+        op = op.input(0).0;
+    }
+}
+
+// Returns the outputs from outside the while loop in frame which enter it.
+fn loop_inputs(frame: &str, exits: &[Output]) -> Result<Vec<Output>> {
+    let mut inputs: Vec<Output> = Vec::new();
+    let mut keys = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut stack: Vec<Operation> = exits.iter().map(|exit| exit.operation.clone()).collect();
+    while let Some(op) = stack.pop() {
+        if !visited.insert(op.name()?) {
+            continue;
+        }
+        let op_type = op.op_type()?;
+        if (op_type == "Enter" || op_type == "RefEnter") && op.get_attr_string("frame_name")? == frame {
+            let (operation, index) = op.input(0);
+            let input = Output { operation, index: index as c_int };
+            if keys.insert(key(&input)?) {
+                inputs.push(input);
+            }
+            continue;
+        }
+        for i in 0..op.num_inputs() {
+            stack.push(op.input(i).0);
+        }
+    }
+    Ok(inputs)
+}
+
+// Returns the names of the operations computing the outputs of a subgraph from its inputs.
+fn subgraph_ops(subgraph: &SubgraphGradient) -> Result<HashSet<String>> {
+    let inputs = subgraph.inputs.iter().map(key).collect::<Result<HashSet<_>>>()?;
+    let mut ops = HashSet::new();
+    let mut stack: Vec<Operation> = subgraph.outputs.iter().map(|output| output.operation.clone()).collect();
+    while let Some(op) = stack.pop() {
+        if !ops.insert(op.name()?) {
+            continue;
+        }
+        for i in 0..op.num_inputs() {
+            let (input, index) = op.input(i);
+            if !inputs.contains(&(input.name()?, index as c_int)) {
+                stack.push(input);
+            }
+        }
+    }
+    Ok(ops)
+}
+
+// Returns the sum of the gradients flowing into output, if any, replacing them with the sum.
+fn total_gradient(graph: &mut Graph,
+                  base: &str,
+                  grads: &mut HashMap<Key, Vec<Output>>,
+                  output: &Output) -> Result<Option<Output>> {
+    let key = key(output)?;
+    let total = match grads.get(&key) {
+        None => return Ok(None),
+        Some(outputs) if outputs.len() == 1 => outputs[0].clone(),
+        Some(outputs) => {
+            let mut add = graph.new_operation("AddN", &graph.new_op_name(&format!("{}/AddN_{{}}", base))?)?;
+            add.add_input_list(outputs);
+            Output { operation: add.finish()?, index: 0 }
+        }
+    };
+    grads.insert(key, vec![total.clone()]);
+    Ok(Some(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sugar::{constant, Gradients};
+    use super::super::{ops, Edge, GraphEdge, Session, SessionOptions, Tensor};
+    use super::*;
+
+    fn gradient(graph: &mut Graph, y: Edge<f32>, x: Edge<f32>) -> Vec<f32> {
+        let grad = Gradients::new(None, vec![y], vec![x]).edges(graph).unwrap();
+        let grad = grad[0].clone().unwrap();
+        let sess = Session::new(&SessionOptions::new(), graph).unwrap();
+        sess.fetch(graph, &grad).unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_op_type_gradient() {
+        let mut graph = Graph::new();
+        // Overrides the gradient of Square, which is 2x, with 3.
+        graph.register_gradient("Square", |graph, op, dys| {
+            let name = op.name()?;
+            let mut three = graph.new_operation("Const", &format!("{}_grad/three", name))?;
+            three.set_attr_type("dtype", op.output_type(0))?;
+            three.set_attr_tensor("value", Tensor::from(3.0f32))?;
+            let three = three.finish()?;
+            let mut grad = graph.new_operation("Mul", &format!("{}_grad/mul", name))?;
+            grad.add_input(dys[0].clone().unwrap());
+            grad.add_input(three);
+            Ok(vec![Some(Output { operation: grad.finish()?, index: 0 })])
+        });
+        let x = constant(vec![1.0f32, 2.0]);
+        let y = ops::Square::new(x.clone()) + ops::Neg::new(x.clone());
+        assert_eq!(gradient(&mut graph, y, x), vec![2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_subgraph_gradient() {
+        let mut graph = Graph::new();
+        // Rounding has no gradient, so it is replaced by the identity.
+        let x = constant(vec![0.4f32, 1.6]);
+        let rounded = ops::Round::new(x.clone());
+        let input = x.output(&mut graph).unwrap();
+        let output = rounded.output(&mut graph).unwrap();
+        graph.register_subgraph_gradient(&[input], &[output], |_, dys| Ok(dys.to_vec()));
+        let y = rounded * constant(vec![2.0f32, 3.0]);
+        assert_eq!(gradient(&mut graph, y, x), vec![2.0, 3.0]);
+    }
+}
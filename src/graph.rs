@@ -1,4 +1,6 @@
 use super::buffer::Buffer;
+use super::control_flow::{cond_input, CondContext};
+use super::gradients::GradientRegistry;
 use super::AnyTensor;
 use super::Code;
 use super::DataType;
@@ -28,6 +30,7 @@ use std::marker::PhantomData;
 use std::mem::swap;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fmt;
 use tensorflow_sys as tf;
 
 #[derive(Debug)]
@@ -276,6 +279,21 @@ impl_drop!(ImportGraphDefResults, TF_DeleteImportGraphDefResults);
 
 ////////////////////////
 
+/// Name of the collection holding variables which are initialised by
+/// `global_variables_initializer`.
+pub const GLOBAL_VARIABLES: &str = "variables";
+
+/// Name of the collection holding variables which should be updated by optimizers.
+pub const TRAINABLE_VARIABLES: &str = "trainable_variables";
+
+/// Name of the collection holding variables which are local to a process, such as those
+/// used by metrics, and are not saved.
+pub const LOCAL_VARIABLES: &str = "local_variables";
+
+/// Name of the collection holding the initializers of lookup tables, which are run by
+/// `lookup::tables_initializer`.
+pub const TABLE_INITIALIZERS: &str = "table_initializer";
+
 /// Represents a computation graph.  Graphs may be shared between sessions.
 /// Graphs are thread-safe when used as directed.
 #[derive(Debug)]
@@ -283,6 +301,18 @@ pub struct Graph {
     gimpl: Arc<GraphImpl>,
     lifetime: GraphLifetime,
     ops: HashMap<usize, Operation>,
+    // Indices of the outputs recorded with `record_output`, by id.
+    output_indices: HashMap<usize, c_int>,
+    collections: HashMap<String, Vec<Operation>>,
+    // Initialisers of variables created with `sugar::Variable`, by variable name.
+    initializers: HashMap<String, Operation>,
+    // Full names of the enclosing name scopes, innermost last.
+    name_scopes: Vec<String>,
+    // Enclosing device scopes, innermost last.
+    devices: Vec<String>,
+    gradients: GradientRegistry,
+    // Enclosing branches of conditionals being added to the graph, innermost last.
+    cond_contexts: Vec<CondContext>,
 }
 
 impl Graph {
@@ -296,6 +326,13 @@ impl Graph {
                 }),
                 lifetime: GraphLifetime,
                 ops: HashMap::new(),
+                output_indices: HashMap::new(),
+                collections: HashMap::new(),
+                initializers: HashMap::new(),
+                name_scopes: Vec::new(),
+                devices: Vec::new(),
+                gradients: GradientRegistry::default(),
+                cond_contexts: Vec::new(),
             }
         }
     }
@@ -397,6 +434,148 @@ impl Graph {
         Some(self.ops.get(&id)?.clone())
     }
 
+    pub(crate) fn record_output(&mut self, id: usize, output: Output) {
+        self.output_indices.insert(id, output.index);
+        self.record_op(id, output.operation);
+    }
+
+    pub(crate) fn get_output_by_id(&self, id: usize) -> Option<Output> {
+        Some(Output {
+            operation: self.get_op_by_id(id)?,
+            index: *self.output_indices.get(&id)?,
+        })
+    }
+
+    /// Runs `f` inside a name scope, so that operations added to the graph by the typed op
+    /// builders while it runs are named `name/...`.
+    ///
+    /// Scopes nest, unless `name` ends with a `/`, in which case it is used as the full scope.
+    /// An empty name returns to the top level. Typed ops are only added to the graph when they,
+    /// or operations using them, are first needed, so `f` should add them, e.g. by calling
+    /// `operation`, for them to be named within the scope.
+    pub fn with_scope<F, R>(&mut self, name: &str, f: F) -> R
+    where F: FnOnce(&mut Graph) -> R, {
+        let scope = if name.is_empty() || name.ends_with('/') {
+            name.trim_end_matches('/').to_string()
+        } else {
+            self.scoped_name(name)
+        };
+        self.name_scopes.push(scope);
+        let result = f(self);
+        self.name_scopes.pop();
+        result
+    }
+
+    /// Runs `f` inside a device scope, so that operations added to the graph by the typed op
+    /// builders while it runs are placed on `device`, e.g. `"/cpu:0"`.
+    ///
+    /// The innermost device scope wins. An empty device clears any enclosing device scope.
+    pub fn with_device<F, R>(&mut self, device: &str, f: F) -> R
+    where F: FnOnce(&mut Graph) -> R, {
+        self.devices.push(device.to_string());
+        let result = f(self);
+        self.devices.pop();
+        result
+    }
+
+    /// Returns the full name of the current name scope, or an empty string at the top level.
+    pub fn name_scope(&self) -> &str {
+        self.name_scopes.last().map(String::as_str).unwrap_or("")
+    }
+
+    /// Returns `name` prefixed with the current name scope.
+    pub fn scoped_name(&self, name: &str) -> String {
+        match self.name_scope() {
+            "" => name.to_string(),
+            scope => format!("{}/{}", scope, name),
+        }
+    }
+
+    /// Returns the device of the current device scope, if any.
+    pub fn device(&self) -> Option<String> {
+        self.devices.last().filter(|device| !device.is_empty()).cloned()
+    }
+
+    /// Adds an operation to the named collection.
+    pub fn add_to_collection(&mut self, name: &str, operation: Operation) {
+        self.collections.entry(name.to_string()).or_insert_with(Vec::new).push(operation);
+    }
+
+    /// Returns the operations in the named collection, in the order they were added.
+    pub fn get_collection(&self, name: &str) -> Vec<Operation> {
+        self.collections.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Returns the variables in the `GLOBAL_VARIABLES` collection.
+    ///
+    /// Variables created with `sugar::Variable` are added to their collections when they are
+    /// added to the graph.
+    pub fn global_variables(&self) -> Vec<Operation> {
+        self.get_collection(GLOBAL_VARIABLES)
+    }
+
+    /// Returns the variables in the `TRAINABLE_VARIABLES` collection.
+    pub fn trainable_variables(&self) -> Vec<Operation> {
+        self.get_collection(TRAINABLE_VARIABLES)
+    }
+
+    /// Returns the variables in the `LOCAL_VARIABLES` collection.
+    pub fn local_variables(&self) -> Vec<Operation> {
+        self.get_collection(LOCAL_VARIABLES)
+    }
+
+    /// Returns the operation which initialises a variable created with `sugar::Variable`.
+    pub fn variable_initializer(&self, variable: &Operation) -> Result<Option<Operation>> {
+        Ok(self.initializers.get(&variable.name()?).cloned())
+    }
+
+    pub(crate) fn set_variable_initializer(&mut self, variable: &Operation, initializer: Operation) -> Result<()> {
+        self.initializers.insert(variable.name()?, initializer);
+        Ok(())
+    }
+
+    /// Registers a gradient function for operations of type `op_type`, which `sugar::Gradients`
+    /// uses instead of the gradient registered in C++, if any. Registering a gradient for a type
+    /// again replaces it.
+    pub fn register_gradient<F>(&mut self, op_type: &str, function: F)
+    where F: Fn(&mut Graph, &Operation, &[Option<Output>]) -> Result<Vec<Option<Output>>>,
+          F: Send + Sync + 'static, {
+        self.gradients.register(op_type, Arc::new(function));
+    }
+
+    /// Registers a gradient function for the subgraph computing `outputs` from `inputs`, which
+    /// `sugar::Gradients` uses instead of differentiating the operations in between.
+    ///
+    /// The function is called with one gradient per output, and must return one gradient per
+    /// input. Gradients don't flow into the subgraph other than through `inputs`.
+    pub fn register_subgraph_gradient<F>(&mut self, inputs: &[Output], outputs: &[Output], function: F)
+    where F: Fn(&mut Graph, &[Option<Output>]) -> Result<Vec<Option<Output>>>,
+          F: Send + Sync + 'static, {
+        self.gradients.register_subgraph(inputs, outputs, Arc::new(function));
+    }
+
+    pub(crate) fn gradient_registry(&self) -> &GradientRegistry {
+        &self.gradients
+    }
+
+    pub(crate) fn cond_contexts(&mut self) -> &mut Vec<CondContext> {
+        &mut self.cond_contexts
+    }
+
+    /// Runs `f` with the conditional branches whose functions were called after the op with `id`
+    /// was created set aside, so the op is added outside them.
+    pub(crate) fn outside_cond_contexts<F, R>(&mut self, id: usize, f: F) -> R
+    where F: FnOnce(&mut Graph) -> R, {
+        let level = self.cond_contexts
+                        .iter()
+                        .position(|context| context.created_outside(id))
+                        .unwrap_or(self.cond_contexts.len());
+        let outside = self.cond_contexts.split_off(level);
+        let result = f(self);
+        self.cond_contexts.extend(outside);
+        result
+    }
+
     /// Iterates over the operations in the graph.
     pub fn operation_iter(&self) -> OperationIter<'_> {
         OperationIter {
@@ -866,6 +1045,13 @@ impl Graph {
             }),
             lifetime: GraphLifetime,
             ops: HashMap::new(),
+            output_indices: HashMap::new(),
+            collections: HashMap::new(),
+            initializers: HashMap::new(),
+            name_scopes: Vec::new(),
+            devices: Vec::new(),
+            gradients: GradientRegistry::default(),
+            cond_contexts: Vec::new(),
         }
     }
 }
@@ -1798,6 +1984,7 @@ impl<'a> OperationDescription<'a> {
 
     pub(crate) fn add_edge<U: TensorType, T: GraphEdge<U>>(&mut self, edge: &T) -> Result<()> {
         let output = edge.output(&mut self.graph)?;
+        let output = cond_input(&mut self.graph, output)?;
         Ok(self.add_input(output))
     }
 
@@ -1813,12 +2000,26 @@ impl<'a> OperationDescription<'a> {
 
     pub(crate) fn add_edge_list<U: TensorType, T: GraphEdge<U>>(&mut self, edge: &[T]) -> Result<()> {
         let output_list: Result<Vec<Output>> = edge.into_iter()
-                                                   .map(|edge| {edge.output(&mut self.graph)})
+                                                   .map(|edge| {
+                                                       let output = edge.output(&mut self.graph)?;
+                                                       cond_input(&mut self.graph, output)
+                                                   })
                                                    .collect();
         self.add_input_list(&output_list?);
         Ok(())
     }
 
+    pub(crate) fn add_any_edge_list(&mut self, edges: &[AnyEdge]) -> Result<()> {
+        let output_list: Result<Vec<Output>> = edges.iter()
+                                                    .map(|edge| {
+                                                        let output = edge.output(&mut self.graph)?;
+                                                        cond_input(&mut self.graph, output)
+                                                    })
+                                                    .collect();
+        self.add_input_list(&output_list?);
+        Ok(())
+    }
+
     /// Adds a control input.
     pub fn add_control_input(&mut self, input: &Operation) {
         unsafe {
@@ -2380,6 +2581,86 @@ impl<T: TensorType> GraphOperation for RefEdge<T> {
 
 impl<T: TensorType> GraphRefEdge<T> for RefEdge<T> {}
 
+impl GraphOperation for Operation {
+    fn tf_operation(&self, _graph: &mut Graph) -> Result<Operation> {
+        Ok(self.clone())
+    }
+}
+
+/// An edge whose element type is only known at runtime.
+///
+/// Ops whose inputs or outputs are described by a `list(type)` attr, such as
+/// `SaveV2` and `RestoreV2`, take and return lists of `AnyEdge`s. Typed edges
+/// can be converted with `From`, and converted back with `typed`.
+#[derive(Clone)]
+pub struct AnyEdge {
+    parent: Rc<dyn GraphOperation>,
+    port: c_int,
+    data_type: DataType,
+}
+
+impl AnyEdge {
+    pub(crate) fn new(parent: Rc<dyn GraphOperation>, port: c_int, data_type: DataType) -> Self {
+        Self {
+            parent,
+            port,
+            data_type,
+        }
+    }
+
+    /// Returns the type of the elements flowing along this edge.
+    pub fn data_type(&self) -> DataType {
+        self.data_type
+    }
+
+    /// Returns the output this edge refers to, adding its operation to `graph` if needed.
+    pub fn output(&self, graph: &mut Graph) -> Result<Output> {
+        Ok(Output {
+            operation: self.parent.tf_operation(graph)?,
+            index: self.port,
+        })
+    }
+
+    /// Converts this edge into a typed edge.
+    ///
+    /// Returns an error if `T` doesn't match the type of the elements flowing along the edge.
+    pub fn typed<T: TensorType>(&self) -> Result<Edge<T>> {
+        if T::data_type() != self.data_type {
+            return Err(invalid_arg!("Expected an edge of type {}, but the edge has type {}",
+                                    T::data_type(),
+                                    self.data_type));
+        }
+        Ok(Edge::new(self.parent.clone(), self.port))
+    }
+}
+
+impl fmt::Debug for AnyEdge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnyEdge")
+         .field("port", &self.port)
+         .field("data_type", &self.data_type)
+         .finish()
+    }
+}
+
+impl GraphOperation for AnyEdge {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.parent.tf_operation(graph)
+    }
+}
+
+impl<T: TensorType> From<Edge<T>> for AnyEdge {
+    fn from(edge: Edge<T>) -> Self {
+        AnyEdge::new(edge.parent, edge.port, T::data_type())
+    }
+}
+
+impl<T: TensorType> From<RefEdge<T>> for AnyEdge {
+    fn from(edge: RefEdge<T>) -> Self {
+        AnyEdge::from(edge.edge)
+    }
+}
+
 ////////////////////////
 
 #[cfg(test)]
@@ -2907,4 +3188,59 @@ mod tests {
             assert!(g.add_gradients(*prefix, &y_outs, &x_outs, None).is_err());
         }
     }
+
+    #[test]
+    fn graph_scopes() {
+        let mut g = Graph::new();
+        let a = super::super::constant::<f32, _>(1.0f32);
+        assert_eq!(a.operation(&mut g).unwrap().name().unwrap(), "Const_0");
+        let (b, c, d) = g.with_scope("layer1", |g| {
+            let b = super::super::ops::Neg::new(a.clone());
+            b.operation(g).unwrap();
+            let c = g.with_device("/cpu:0", |g| {
+                g.with_scope("inner", |g| {
+                    let mut c = super::super::ops::Neg::build(b.clone());
+                    c.op_name("c");
+                    let c = c.finish();
+                    c.operation(g).unwrap();
+                    c
+                })
+            });
+            let d = g.with_scope("other/", |g| {
+                let d = super::super::ops::Neg::new(c.clone());
+                d.operation(g).unwrap();
+                d
+            });
+            assert_eq!(g.name_scope(), "layer1");
+            (b, c, d)
+        });
+        assert_eq!(g.name_scope(), "");
+        assert_eq!(g.device(), None);
+
+        assert_eq!(b.operation(&mut g).unwrap().name().unwrap(), "layer1/Neg_0");
+        let c = c.operation(&mut g).unwrap();
+        assert_eq!(c.name().unwrap(), "layer1/inner/c");
+        assert_eq!(c.device().unwrap(), "/cpu:0");
+        let d = d.operation(&mut g).unwrap();
+        assert_eq!(d.name().unwrap(), "other/Neg_0");
+        assert_eq!(d.device().unwrap(), "");
+    }
+
+    #[test]
+    fn any_edge_type_list() {
+        let mut g = Graph::new();
+        let a = super::super::constant::<f32, _>(1.0f32);
+        let b = super::super::constant::<i32, _>(2);
+        let outputs = super::super::ops::IdentityN::new(&[a.into(), b.into()]);
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].data_type(), DataType::Float);
+        assert_eq!(outputs[1].data_type(), DataType::Int32);
+        assert!(outputs[0].typed::<f32>().is_ok());
+        assert!(outputs[1].typed::<f32>().is_err());
+
+        let output = outputs[1].output(&mut g).unwrap();
+        assert_eq!(output.index, 1);
+        assert_eq!(output.operation.op_type().unwrap(), "IdentityN");
+        assert_eq!(output.operation.num_inputs(), 2);
+    }
 }
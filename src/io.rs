@@ -1,13 +1,30 @@
-//! A module for writing TFRecords, Tensorflow's preferred on-disk data format.
+//! A module for reading and writing TFRecords, Tensorflow's preferred on-disk data format.
 //!
 //! See the [tensorflow docs](https://www.tensorflow.org/api_guides/python/python_io#tfrecords-format-details) for details of this format.
 
 use byteorder;
 use crc::crc32;
+use flate2::read::MultiGzDecoder;
+use flate2::read::ZlibDecoder;
+use flate2::write::GzEncoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::error::Error;
+use std::fmt;
 use std::io;
+use std::io::Read;
 use std::io::Write;
+use self::byteorder::ReadBytesExt;
 use self::byteorder::WriteBytesExt;
 
+pub mod checkpoint;
+pub use self::checkpoint::CheckpointReader;
+
+pub mod example;
+pub use self::example::Example;
+pub use self::example::Feature;
+pub use self::example::SequenceExample;
+
 /// A type for writing bytes in the TFRecords format.
 #[derive(Debug)]
 pub struct RecordWriter<W: Write> {
@@ -36,23 +53,377 @@ impl<W> RecordWriter<W> where W: Write {
         let mut len_bytes = [0u8; 8];
         (&mut len_bytes[..]).write_u64::<byteorder::LittleEndian>(bytes.len() as u64)?;
 
-        let masked_len_crc32c = Self::mask(crc32::checksum_castagnoli(&len_bytes));
+        let masked_len_crc32c = mask(crc32::checksum_castagnoli(&len_bytes));
         let mut len_crc32_bytes = [0u8; 4];
         (&mut len_crc32_bytes[..]).write_u32::<byteorder::LittleEndian>(masked_len_crc32c)?;
 
-        let masked_bytes_crc32c = Self::mask(crc32::checksum_castagnoli(&bytes));
+        let masked_bytes_crc32c = mask(crc32::checksum_castagnoli(&bytes));
         let mut bytes_crc32_bytes = [0u8; 4];
         (& mut bytes_crc32_bytes[..]).write_u32::<byteorder::LittleEndian>(masked_bytes_crc32c)?;
 
-        self.writer.write(&len_bytes)?;
-        self.writer.write(&len_crc32_bytes)?;
-        self.writer.write(bytes)?;
-        self.writer.write(&bytes_crc32_bytes)?;
+        self.writer.write_all(&len_bytes)?;
+        self.writer.write_all(&len_crc32_bytes)?;
+        self.writer.write_all(bytes)?;
+        self.writer.write_all(&bytes_crc32_bytes)?;
         Ok(())
     }
+}
+
+impl<W> RecordWriter<CompressedWriter<W>> where W: Write {
+    /// Construct a new RecordWriter which compresses records according to
+    /// `options` before writing them to `writer`.
+    pub fn with_options(writer: W, options: &RecordOptions) -> Self {
+        RecordWriter::new(CompressedWriter::new(writer, options))
+    }
+
+    /// Flushes any remaining compressed data (including the GZIP trailer) and
+    /// returns the underlying writer.
+    ///
+    /// Dropping the RecordWriter also finishes the stream, but silently
+    /// ignores any errors.
+    pub fn finish(self) -> io::Result<W> {
+        self.writer.finish()
+    }
+}
+
+fn mask(crc: u32) -> u32 {
+    ((crc >> 15) | (crc << 17)).wrapping_add(0xa282ead8u32)
+}
+
+////////////////////////
+
+/// The compression applied to a whole TFRecords file, matching
+/// `tf.io.TFRecordCompressionType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompressionType {
+    /// Records are stored uncompressed.
+    None,
+    /// The file is a single zlib stream.
+    Zlib,
+    /// The file is a gzip stream.
+    Gzip,
+}
+
+impl Default for CompressionType {
+    fn default() -> Self {
+        CompressionType::None
+    }
+}
+
+/// Options for reading and writing TFRecords files, matching
+/// `tf.io.TFRecordOptions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecordOptions {
+    compression_type: CompressionType,
+    compression_level: Option<u32>,
+}
+
+impl RecordOptions {
+    /// Creates options for an uncompressed file.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the compression type.
+    pub fn compression_type(mut self, compression_type: CompressionType) -> Self {
+        self.compression_type = compression_type;
+        self
+    }
+
+    /// Sets the compression level, from 0 (no compression) to 9 (best
+    /// compression).  If unset, the zlib default is used.  Ignored when
+    /// reading.
+    pub fn compression_level(mut self, level: u32) -> Self {
+        self.compression_level = Some(level);
+        self
+    }
+
+    /// Returns the compression type.
+    pub fn get_compression_type(&self) -> CompressionType {
+        self.compression_type
+    }
+
+    fn compression(&self) -> Compression {
+        match self.compression_level {
+            Some(level) => Compression::new(level),
+            None => Compression::default(),
+        }
+    }
+}
+
+/// A writer which applies the compression selected by `RecordOptions`.
+#[derive(Debug)]
+pub enum CompressedWriter<W: Write> {
+    /// Uncompressed output.
+    None(W),
+    /// Zlib compressed output.
+    Zlib(ZlibEncoder<W>),
+    /// Gzip compressed output.
+    Gzip(GzEncoder<W>),
+}
+
+impl<W> CompressedWriter<W> where W: Write {
+    /// Wraps `writer` according to `options`.
+    pub fn new(writer: W, options: &RecordOptions) -> Self {
+        match options.compression_type {
+            CompressionType::None => CompressedWriter::None(writer),
+            CompressionType::Zlib =>
+                CompressedWriter::Zlib(ZlibEncoder::new(writer, options.compression())),
+            CompressionType::Gzip =>
+                CompressedWriter::Gzip(GzEncoder::new(writer, options.compression())),
+        }
+    }
+
+    /// Finishes the compressed stream and returns the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            CompressedWriter::None(mut w) => {
+                w.flush()?;
+                Ok(w)
+            }
+            CompressedWriter::Zlib(w) => w.finish(),
+            CompressedWriter::Gzip(w) => w.finish(),
+        }
+    }
+}
+
+impl<W> Write for CompressedWriter<W> where W: Write {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match *self {
+            CompressedWriter::None(ref mut w) => w.write(buf),
+            CompressedWriter::Zlib(ref mut w) => w.write(buf),
+            CompressedWriter::Gzip(ref mut w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match *self {
+            CompressedWriter::None(ref mut w) => w.flush(),
+            CompressedWriter::Zlib(ref mut w) => w.flush(),
+            CompressedWriter::Gzip(ref mut w) => w.flush(),
+        }
+    }
+}
+
+/// A reader which undoes the compression selected by `RecordOptions`.
+#[derive(Debug)]
+pub enum DecompressedReader<R: Read> {
+    /// Uncompressed input.
+    None(R),
+    /// Zlib compressed input.
+    Zlib(ZlibDecoder<R>),
+    /// Gzip compressed input.
+    Gzip(MultiGzDecoder<R>),
+}
+
+impl<R> DecompressedReader<R> where R: Read {
+    /// Wraps `reader` according to `options`.
+    pub fn new(reader: R, options: &RecordOptions) -> Self {
+        match options.compression_type {
+            CompressionType::None => DecompressedReader::None(reader),
+            CompressionType::Zlib => DecompressedReader::Zlib(ZlibDecoder::new(reader)),
+            CompressionType::Gzip => DecompressedReader::Gzip(MultiGzDecoder::new(reader)),
+        }
+    }
+}
+
+impl<R> Read for DecompressedReader<R> where R: Read {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match *self {
+            DecompressedReader::None(ref mut r) => r.read(buf),
+            DecompressedReader::Zlib(ref mut r) => r.read(buf),
+            DecompressedReader::Gzip(ref mut r) => r.read(buf),
+        }
+    }
+}
+
+////////////////////////
+
+/// An error encountered while reading a TFRecord.
+///
+/// Offsets are measured in bytes from the position of the underlying reader
+/// when the `RecordReader` was constructed, and point at the start of the
+/// record in which the problem was found.  For compressed files, offsets are
+/// positions in the decompressed stream.
+#[derive(Debug)]
+pub enum RecordReadError {
+    /// The stream ended part way through the record.
+    Truncated {
+        /// Offset of the start of the record.
+        offset: u64,
+    },
+    /// The masked CRC32C of the length header did not match.
+    CorruptLength {
+        /// Offset of the start of the record.
+        offset: u64,
+    },
+    /// The masked CRC32C of the record data did not match.
+    CorruptData {
+        /// Offset of the start of the record.
+        offset: u64,
+    },
+    /// The underlying reader returned an error.
+    Io {
+        /// Offset of the start of the record.
+        offset: u64,
+        /// The error returned by the reader.
+        source: io::Error,
+    },
+}
+
+impl RecordReadError {
+    /// Returns the offset of the start of the record which caused the error.
+    pub fn offset(&self) -> u64 {
+        match *self {
+            RecordReadError::Truncated { offset } => offset,
+            RecordReadError::CorruptLength { offset } => offset,
+            RecordReadError::CorruptData { offset } => offset,
+            RecordReadError::Io { offset, .. } => offset,
+        }
+    }
+}
+
+impl fmt::Display for RecordReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RecordReadError::Truncated { offset } =>
+                write!(f, "truncated record at offset {}", offset),
+            RecordReadError::CorruptLength { offset } =>
+                write!(f, "corrupt record length at offset {}", offset),
+            RecordReadError::CorruptData { offset } =>
+                write!(f, "corrupt record data at offset {}", offset),
+            RecordReadError::Io { offset, ref source } =>
+                write!(f, "error reading record at offset {}: {}", offset, source),
+        }
+    }
+}
+
+impl Error for RecordReadError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordReadError::Truncated { .. } => "truncated record",
+            RecordReadError::CorruptLength { .. } => "corrupt record length",
+            RecordReadError::CorruptData { .. } => "corrupt record data",
+            RecordReadError::Io { .. } => "error reading record",
+        }
+    }
 
-    fn mask(crc: u32) -> u32 {
-        ((crc >> 15) | (crc << 17)).wrapping_add(0xa282ead8u32)
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            RecordReadError::Io { ref source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A type for reading bytes in the TFRecords format.
+///
+/// Both the length and the data CRCs are verified for every record.
+///
+/// As an iterator, the reader yields each record until the stream ends or
+/// the first error, after which it returns `None`, since the position of the
+/// next record can't be trusted.
+#[derive(Debug)]
+pub struct RecordReader<R: Read> {
+    reader: R,
+    offset: u64,
+    failed: bool,
+}
+
+impl<R> RecordReader<R> where R: Read {
+    /// Construct a new RecordReader which reads from `reader`.
+    pub fn new(reader: R) -> Self {
+        RecordReader { reader, offset: 0, failed: false }
+    }
+
+    /// Returns the offset of the next record to be read.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Read a complete TFRecord.  Returns `Ok(None)` if the stream ended
+    /// cleanly before the start of a record.
+    pub fn read_record(&mut self) -> Result<Option<Vec<u8>>, RecordReadError> {
+        let start = self.offset;
+
+        let mut len_bytes = [0u8; 8];
+        match self.read_header(&mut len_bytes, start)? {
+            0 => return Ok(None),
+            n if n < len_bytes.len() => return Err(RecordReadError::Truncated { offset: start }),
+            _ => {}
+        }
+        let masked_len_crc32c = self.read_u32(start)?;
+        if mask(crc32::checksum_castagnoli(&len_bytes)) != masked_len_crc32c {
+            return Err(RecordReadError::CorruptLength { offset: start });
+        }
+        let len = (&len_bytes[..]).read_u64::<byteorder::LittleEndian>()
+            .map_err(|source| RecordReadError::Io { offset: start, source })?;
+
+        // Read through `take` rather than allocating `len` bytes up front, so
+        // that a bogus length in a truncated file can't exhaust memory.
+        let mut bytes = Vec::new();
+        let read = (&mut self.reader).take(len).read_to_end(&mut bytes)
+            .map_err(|source| RecordReadError::Io { offset: start, source })?;
+        self.offset += read as u64;
+        if (read as u64) < len {
+            return Err(RecordReadError::Truncated { offset: start });
+        }
+
+        let masked_bytes_crc32c = self.read_u32(start)?;
+        if mask(crc32::checksum_castagnoli(&bytes)) != masked_bytes_crc32c {
+            return Err(RecordReadError::CorruptData { offset: start });
+        }
+        Ok(Some(bytes))
+    }
+
+    /// Fills `buf` as far as possible, returning the number of bytes read.
+    fn read_header(&mut self, buf: &mut [u8], start: u64) -> Result<usize, RecordReadError> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(source) => return Err(RecordReadError::Io { offset: start, source }),
+            }
+        }
+        self.offset += filled as u64;
+        Ok(filled)
+    }
+
+    fn read_u32(&mut self, start: u64) -> Result<u32, RecordReadError> {
+        let mut bytes = [0u8; 4];
+        if self.read_header(&mut bytes, start)? < bytes.len() {
+            return Err(RecordReadError::Truncated { offset: start });
+        }
+        (&bytes[..]).read_u32::<byteorder::LittleEndian>()
+            .map_err(|source| RecordReadError::Io { offset: start, source })
+    }
+}
+
+impl<R> RecordReader<DecompressedReader<R>> where R: Read {
+    /// Construct a new RecordReader which decompresses `reader` according to
+    /// `options`.
+    pub fn with_options(reader: R, options: &RecordOptions) -> Self {
+        RecordReader::new(DecompressedReader::new(reader, options))
+    }
+}
+
+impl<R> Iterator for RecordReader<R> where R: Read {
+    type Item = Result<Vec<u8>, RecordReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.read_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => None,
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
     }
 }
 
@@ -90,4 +461,134 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn reader_reads_python_output() {
+        // This file was generated by test_resources/io/python_writer.py
+        let f = File::open("test_resources/io/expected.tfrecord").unwrap();
+        let records: Vec<Vec<u8>> = RecordReader::new(::std::io::BufReader::new(f))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(records, vec!["The Quick Brown Fox".as_bytes().to_vec()]);
+    }
+
+    #[test]
+    fn reader_round_trip() {
+        let mut buf = Vec::new();
+        {
+            let mut record_writer = RecordWriter::new(&mut buf);
+            record_writer.write_record(b"first").unwrap();
+            record_writer.write_record(b"").unwrap();
+            record_writer.write_record(b"third record").unwrap();
+        }
+        let mut record_reader = RecordReader::new(&buf[..]);
+        assert_eq!(record_reader.read_record().unwrap(), Some(b"first".to_vec()));
+        assert_eq!(record_reader.offset(), 8 + 4 + 5 + 4);
+        assert_eq!(record_reader.read_record().unwrap(), Some(vec![]));
+        assert_eq!(record_reader.read_record().unwrap(), Some(b"third record".to_vec()));
+        assert_eq!(record_reader.read_record().unwrap(), None);
+    }
+
+    #[test]
+    fn reader_detects_corruption() {
+        let mut buf = Vec::new();
+        RecordWriter::new(&mut buf).write_record(b"first").unwrap();
+        RecordWriter::new(&mut buf).write_record(b"second").unwrap();
+        let second = 8 + 4 + 5 + 4;
+
+        let mut bad_data = buf.clone();
+        bad_data[second + 12] ^= 1;
+        let mut record_reader = RecordReader::new(&bad_data[..]);
+        assert!(record_reader.read_record().is_ok());
+        match record_reader.read_record() {
+            Err(RecordReadError::CorruptData { offset }) => assert_eq!(offset, second as u64),
+            other => panic!("Unexpected result {:?}", other),
+        }
+
+        let mut bad_len = buf.clone();
+        bad_len[second] ^= 1;
+        let mut record_reader = RecordReader::new(&bad_len[..]);
+        assert!(record_reader.read_record().is_ok());
+        match record_reader.read_record() {
+            Err(RecordReadError::CorruptLength { offset }) => assert_eq!(offset, second as u64),
+            other => panic!("Unexpected result {:?}", other),
+        }
+
+        for len in &[second + 3, second + 14, buf.len() - 1] {
+            let mut record_reader = RecordReader::new(&buf[..*len]);
+            assert!(record_reader.read_record().is_ok());
+            match record_reader.read_record() {
+                Err(RecordReadError::Truncated { offset }) => assert_eq!(offset, second as u64),
+                other => panic!("Unexpected result {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn iterator_ends_after_error() {
+        let mut buf = Vec::new();
+        RecordWriter::new(&mut buf).write_record(b"first").unwrap();
+        RecordWriter::new(&mut buf).write_record(b"second").unwrap();
+        RecordWriter::new(&mut buf).write_record(b"third").unwrap();
+        let second = 8 + 4 + 5 + 4;
+        buf[second] ^= 1;
+
+        let mut record_reader = RecordReader::new(&buf[..]);
+        assert_eq!(record_reader.next().unwrap().unwrap(), b"first".to_vec());
+        match record_reader.next() {
+            Some(Err(RecordReadError::CorruptLength { offset })) => assert_eq!(offset, second as u64),
+            other => panic!("Unexpected result {:?}", other),
+        }
+        assert!(record_reader.next().is_none());
+        assert!(record_reader.next().is_none());
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        let records: Vec<&[u8]> = vec![b"The Quick Brown Fox", b"", b"jumps over the lazy dog"];
+        let mut uncompressed = Vec::new();
+        {
+            let mut record_writer = RecordWriter::new(&mut uncompressed);
+            for record in &records {
+                record_writer.write_record(record).unwrap();
+            }
+        }
+
+        for compression_type in &[CompressionType::None, CompressionType::Zlib, CompressionType::Gzip] {
+            let options = RecordOptions::new().compression_type(*compression_type);
+            let mut record_writer = RecordWriter::with_options(Vec::new(), &options);
+            for record in &records {
+                record_writer.write_record(record).unwrap();
+            }
+            let compressed = record_writer.finish().unwrap();
+
+            // The compressed stream must wrap exactly the uncompressed format.
+            let mut decompressed = Vec::new();
+            DecompressedReader::new(&compressed[..], &options)
+                .read_to_end(&mut decompressed)
+                .unwrap();
+            assert_eq!(decompressed, uncompressed, "{:?}", compression_type);
+
+            let actual: Vec<Vec<u8>> = RecordReader::with_options(&compressed[..], &options)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            let expected: Vec<Vec<u8>> = records.iter().map(|r| r.to_vec()).collect();
+            assert_eq!(actual, expected, "{:?}", compression_type);
+        }
+    }
+
+    #[test]
+    fn compressed_formats() {
+        let options = RecordOptions::new().compression_type(CompressionType::Gzip);
+        let mut record_writer = RecordWriter::with_options(Vec::new(), &options);
+        record_writer.write_record(b"x").unwrap();
+        let gzip = record_writer.finish().unwrap();
+        assert_eq!(&gzip[..2], &[0x1f, 0x8b]);
+
+        let options = RecordOptions::new().compression_type(CompressionType::Zlib);
+        let mut record_writer = RecordWriter::with_options(Vec::new(), &options);
+        record_writer.write_record(b"x").unwrap();
+        let zlib = record_writer.finish().unwrap();
+        assert_eq!(zlib[0] & 0x0f, 8); // deflate
+        assert_eq!(((zlib[0] as u16) << 8 | zlib[1] as u16) % 31, 0);
+    }
 }
@@ -0,0 +1,439 @@
+//! Reading of V2 checkpoints without a graph or session.
+//!
+//! A checkpoint with the path prefix `model.ckpt` consists of an index file,
+//! `model.ckpt.index`, and one or more data files such as
+//! `model.ckpt.data-00000-of-00001`. The index is an SSTable which maps each
+//! tensor name to a `BundleEntryProto` describing where the tensor's bytes are
+//! stored, e.g.
+//!
+//! ```rust,ignore
+//! let reader = CheckpointReader::open("/tmp/model.ckpt")?;
+//! for name in reader.names() {
+//!     println!("{}: {} {}", name, reader.data_type(name)?, reader.shape(name)?);
+//! }
+//! let weights: Tensor<f32> = reader.tensor("dense/kernel")?;
+//! ```
+
+use super::super::{Code, DataType, Result, Shape, Status, Tensor, TensorType};
+use super::mask;
+use byteorder::ByteOrder;
+use byteorder::LittleEndian;
+use crc::crc32;
+use protobuf::Message;
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::mem::size_of;
+use std::path::Path;
+use std::path::PathBuf;
+use std::ptr;
+use tensorflow_protos::tensor_bundle::BundleEntryProto;
+use tensorflow_protos::tensor_bundle::BundleHeaderProto;
+use tensorflow_protos::tensor_bundle::BundleHeaderProto_Endianness;
+
+/// Magic number at the end of an SSTable footer.
+const TABLE_MAGIC_NUMBER: u64 = 0xdb4775248b80fb57;
+
+/// Size of an SSTable footer: two padded block handles and the magic number.
+const FOOTER_SIZE: usize = 48;
+
+/// Size of the compression type and checksum following each SSTable block.
+const BLOCK_TRAILER_SIZE: usize = 5;
+
+macro_rules! data_loss {
+    ($($arg:tt)*) => {
+        Status::new_set(Code::DataLoss, &format!($($arg)*)).unwrap()
+    };
+}
+
+/// Reads the tensors stored in a V2 checkpoint, as written by `train::Saver`
+/// or Python's `tf.train.Saver`.
+#[derive(Debug)]
+pub struct CheckpointReader {
+    prefix: PathBuf,
+    header: BundleHeaderProto,
+    entries: BTreeMap<String, BundleEntryProto>,
+}
+
+impl CheckpointReader {
+    /// Opens the checkpoint with the given path prefix, reading its index.
+    pub fn open<P: AsRef<Path>>(prefix: P) -> Result<Self> {
+        let prefix = prefix.as_ref().to_path_buf();
+        let mut index_path = prefix.clone().into_os_string();
+        index_path.push(".index");
+        let table = fs::read(index_path)?;
+
+        let mut header = None;
+        let mut entries = BTreeMap::new();
+        for (key, value) in read_table(&table)? {
+            if key.is_empty() {
+                let mut proto = BundleHeaderProto::new();
+                proto.merge_from_bytes(value)?;
+                header = Some(proto);
+            } else {
+                let name = String::from_utf8(key)
+                    .map_err(|_| data_loss!("Tensor name in checkpoint is not valid UTF-8"))?;
+                let mut proto = BundleEntryProto::new();
+                proto.merge_from_bytes(value)?;
+                entries.insert(name, proto);
+            }
+        }
+        let header = header.ok_or_else(|| data_loss!("Checkpoint index has no header"))?;
+        if header.get_endianness() != BundleHeaderProto_Endianness::LITTLE {
+            return Err(Status::new_set(Code::Unimplemented,
+                                       "Big-endian checkpoints are not supported").unwrap());
+        }
+        Ok(CheckpointReader {
+            prefix,
+            header,
+            entries,
+        })
+    }
+
+    /// Returns the names of the tensors in the checkpoint, in sorted order.
+    pub fn names(&self) -> Vec<&str> {
+        self.entries.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Returns true if the checkpoint contains a tensor with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Returns the type of the named tensor.
+    pub fn data_type(&self, name: &str) -> Result<DataType> {
+        Ok(DataType::from_proto(self.entry(name)?.get_dtype()))
+    }
+
+    /// Returns the shape of the named tensor.
+    pub fn shape(&self, name: &str) -> Result<Shape> {
+        Ok(Shape::from_proto(self.entry(name)?.get_shape()))
+    }
+
+    /// Reads the named tensor from the checkpoint's data files.
+    ///
+    /// Returns an error if `T` doesn't match the type of the tensor.
+    pub fn tensor<T: TensorType>(&self, name: &str) -> Result<Tensor<T>> {
+        let entry = self.entry(name)?;
+        let data_type = self.data_type(name)?;
+        if data_type != T::data_type() {
+            return Err(invalid_arg!("Tensor {} has type {}, not {}", name, data_type, T::data_type()));
+        }
+        if !entry.get_slices().is_empty() {
+            return Err(Status::new_set(Code::Unimplemented,
+                                       &format!("Tensor {} is partitioned, which is not supported", name))
+                .unwrap());
+        }
+        let mut dims = Vec::new();
+        for dim in entry.get_shape().get_dim() {
+            if dim.get_size() < 0 {
+                return Err(data_loss!("Tensor {} has an unknown dimension", name));
+            }
+            dims.push(dim.get_size() as u64);
+        }
+        let count = dims.iter().product::<u64>() as usize;
+        let data = self.read_data(name, entry)?;
+
+        let mut tensor = Tensor::<T>::new(&dims);
+        if T::is_repr_c() {
+            if data.len() != count * size_of::<T>() {
+                return Err(data_loss!("Tensor {} has {} bytes, expected {}",
+                                      name,
+                                      data.len(),
+                                      count * size_of::<T>()));
+            }
+            check_crc32c(name, entry, crc32::checksum_castagnoli(&data))?;
+            unsafe {
+                ptr::copy_nonoverlapping(data.as_ptr(), tensor.as_mut_ptr() as *mut u8, data.len());
+            }
+        } else if data_type == DataType::String {
+            let strings = read_strings(name, entry, &data, count)?;
+            tensor = tensor.with_values(&T::unpack(&encode_strings(&strings), count)?)?;
+        } else {
+            return Err(Status::new_set(Code::Unimplemented,
+                                       &format!("Reading tensors of type {} is not supported", data_type))
+                .unwrap());
+        }
+        Ok(tensor)
+    }
+
+    fn entry(&self, name: &str) -> Result<&BundleEntryProto> {
+        self.entries.get(name).ok_or_else(|| {
+            Status::new_set(Code::NotFound,
+                            &format!("Tensor {} not found in checkpoint {}", name, self.prefix.display()))
+                .unwrap()
+        })
+    }
+
+    fn read_data(&self, name: &str, entry: &BundleEntryProto) -> Result<Vec<u8>> {
+        if entry.get_offset() < 0 || entry.get_size() < 0 {
+            return Err(data_loss!("Tensor {} has an invalid location", name));
+        }
+        let mut path = self.prefix.clone().into_os_string();
+        path.push(format!(".data-{:05}-of-{:05}", entry.get_shard_id(), self.header.get_num_shards()));
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::Start(entry.get_offset() as u64))?;
+        let mut data = vec![0; entry.get_size() as usize];
+        file.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
+
+fn check_crc32c(name: &str, entry: &BundleEntryProto, crc: u32) -> Result<()> {
+    if mask(crc) != entry.get_crc32c() {
+        return Err(data_loss!("Checksum mismatch for tensor {}", name));
+    }
+    Ok(())
+}
+
+/// Decodes a string tensor, stored as the varint64 lengths of the strings, a
+/// checksum of the lengths, and the concatenated bytes of the strings.
+fn read_strings<'a>(name: &str,
+                    entry: &BundleEntryProto,
+                    data: &'a [u8],
+                    count: usize)
+                    -> Result<Vec<&'a [u8]>> {
+    let mut pos = 0;
+    let mut lengths = Vec::with_capacity(count);
+    // The checksum of the tensor covers the lengths as fixed-width integers.
+    let mut crc = 0;
+    for _ in 0..count {
+        let length = read_varint64(data, &mut pos)?;
+        let mut bytes = [0u8; 8];
+        LittleEndian::write_u64(&mut bytes, length);
+        crc = crc32::update(crc, &crc32::CASTAGNOLI_TABLE, &bytes);
+        lengths.push(length as usize);
+    }
+    let lengths_crc = data.get(pos..pos + 4)
+        .ok_or_else(|| data_loss!("Tensor {} is truncated", name))?;
+    if LittleEndian::read_u32(lengths_crc) != crc32::checksum_castagnoli(&data[..pos]) {
+        return Err(data_loss!("Checksum mismatch for the string lengths of tensor {}", name));
+    }
+    crc = crc32::update(crc, &crc32::CASTAGNOLI_TABLE, lengths_crc);
+    pos += 4;
+
+    let mut strings = Vec::with_capacity(count);
+    for length in lengths {
+        let string = data.get(pos..pos + length)
+            .ok_or_else(|| data_loss!("Tensor {} is truncated", name))?;
+        crc = crc32::update(crc, &crc32::CASTAGNOLI_TABLE, string);
+        strings.push(string);
+        pos += length;
+    }
+    check_crc32c(name, entry, crc)?;
+    Ok(strings)
+}
+
+/// Encodes strings the way the C API lays out string tensors, so they can be
+/// decoded by `TensorType::unpack`.
+fn encode_strings(strings: &[&[u8]]) -> Vec<u8> {
+    let mut offsets = Vec::new();
+    let mut contents = Vec::new();
+    for string in strings {
+        let mut bytes = [0u8; 8];
+        LittleEndian::write_u64(&mut bytes, contents.len() as u64);
+        offsets.extend_from_slice(&bytes);
+        let mut length = string.len() as u64;
+        while length >= 0x80 {
+            contents.push((length as u8) | 0x80);
+            length >>= 7;
+        }
+        contents.push(length as u8);
+        contents.extend_from_slice(string);
+    }
+    offsets.extend_from_slice(&contents);
+    offsets
+}
+
+fn read_varint64(data: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut result = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*pos).ok_or_else(|| data_loss!("Truncated varint"))?;
+        *pos += 1;
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err(data_loss!("Varint is too long"))
+}
+
+fn read_varint32(data: &[u8], pos: &mut usize) -> Result<usize> {
+    let value = read_varint64(data, pos)?;
+    if value > u64::from(u32::max_value()) {
+        return Err(data_loss!("Varint is too long"));
+    }
+    Ok(value as usize)
+}
+
+/// The location of a block within an SSTable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BlockHandle {
+    offset: usize,
+    size: usize,
+}
+
+impl BlockHandle {
+    fn decode(data: &[u8], pos: &mut usize) -> Result<Self> {
+        Ok(BlockHandle {
+            offset: read_varint64(data, pos)? as usize,
+            size: read_varint64(data, pos)? as usize,
+        })
+    }
+}
+
+/// Returns the contents of a block, after verifying its checksum.
+fn read_block(table: &[u8], handle: BlockHandle) -> Result<&[u8]> {
+    let end = handle.offset
+        .checked_add(handle.size)
+        .filter(|end| end + BLOCK_TRAILER_SIZE <= table.len())
+        .ok_or_else(|| data_loss!("Block extends past the end of the checkpoint index"))?;
+    let compression = table[end];
+    let crc = LittleEndian::read_u32(&table[end + 1..end + BLOCK_TRAILER_SIZE]);
+    if mask(crc32::checksum_castagnoli(&table[handle.offset..end + 1])) != crc {
+        return Err(data_loss!("Checksum mismatch in checkpoint index block"));
+    }
+    if compression != 0 {
+        return Err(Status::new_set(Code::Unimplemented,
+                                   "Compressed checkpoint indexes are not supported").unwrap());
+    }
+    Ok(&table[handle.offset..end])
+}
+
+/// Returns the key/value pairs stored in a block.
+///
+/// Keys are prefix-compressed: each entry stores the length of the prefix it
+/// shares with the previous key, followed by the rest of the key. The block
+/// ends with an array of restart points, which are only needed for seeking.
+fn read_block_entries(block: &[u8]) -> Result<Vec<(Vec<u8>, &[u8])>> {
+    if block.len() < 4 {
+        return Err(data_loss!("Checkpoint index block is too short"));
+    }
+    let num_restarts = LittleEndian::read_u32(&block[block.len() - 4..]) as usize;
+    let limit = num_restarts.checked_mul(4)
+        .and_then(|size| (block.len() - 4).checked_sub(size))
+        .ok_or_else(|| data_loss!("Checkpoint index block has too many restart points"))?;
+
+    let mut entries = Vec::new();
+    let mut key: Vec<u8> = Vec::new();
+    let mut pos = 0;
+    while pos < limit {
+        let shared = read_varint32(block, &mut pos)?;
+        let non_shared = read_varint32(block, &mut pos)?;
+        let value_length = read_varint32(block, &mut pos)?;
+        if shared > key.len() || pos + non_shared + value_length > limit {
+            return Err(data_loss!("Corrupt entry in checkpoint index block"));
+        }
+        key.truncate(shared);
+        key.extend_from_slice(&block[pos..pos + non_shared]);
+        pos += non_shared;
+        entries.push((key.clone(), &block[pos..pos + value_length]));
+        pos += value_length;
+    }
+    Ok(entries)
+}
+
+/// Returns all key/value pairs stored in an SSTable, in order.
+fn read_table(table: &[u8]) -> Result<Vec<(Vec<u8>, &[u8])>> {
+    if table.len() < FOOTER_SIZE {
+        return Err(data_loss!("Checkpoint index is too short"));
+    }
+    let footer = &table[table.len() - FOOTER_SIZE..];
+    if LittleEndian::read_u64(&footer[FOOTER_SIZE - 8..]) != TABLE_MAGIC_NUMBER {
+        return Err(data_loss!("Checkpoint index is not an SSTable"));
+    }
+    let mut pos = 0;
+    let _metaindex = BlockHandle::decode(footer, &mut pos)?;
+    let index = BlockHandle::decode(footer, &mut pos)?;
+
+    let mut entries = Vec::new();
+    for (_, handle) in read_block_entries(read_block(table, index)?)? {
+        let handle = BlockHandle::decode(handle, &mut 0)?;
+        entries.extend(read_block_entries(read_block(table, handle)?)?);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops;
+    use crate::{constant, Graph, Saver, Session, SessionOptions, SessionRun, Variable};
+    use crate::ConstantInitialiser;
+
+    #[test]
+    fn read_varints() {
+        let data = [0x96, 0x01, 0x7f, 0xff, 0xff, 0xff, 0xff, 0x0f];
+        let mut pos = 0;
+        assert_eq!(read_varint64(&data, &mut pos).unwrap(), 150);
+        assert_eq!(read_varint64(&data, &mut pos).unwrap(), 127);
+        assert_eq!(read_varint32(&data, &mut pos).unwrap(), 0xffffffff);
+        assert!(read_varint64(&data, &mut pos).is_err());
+    }
+
+    #[test]
+    fn block_entries_share_prefixes() {
+        // "apple" => "1", "apricot" => "22", then one restart point at 0.
+        let mut block = vec![0, 5, 1];
+        block.extend_from_slice(b"apple1");
+        block.extend_from_slice(&[2, 5, 2]);
+        block.extend_from_slice(b"ricot22");
+        block.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0]);
+        let entries = read_block_entries(&block).unwrap();
+        assert_eq!(entries,
+                   vec![(b"apple".to_vec(), &b"1"[..]), (b"apricot".to_vec(), &b"22"[..])]);
+    }
+
+    #[test]
+    fn read_saved_checkpoint() {
+        let dir = std::env::temp_dir()
+            .join(format!("tensorflow_rust_checkpoint_reader_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("model.ckpt");
+
+        let mut graph = Graph::new();
+        let mut weights = Variable::<f32>::build(&[2, 3], ConstantInitialiser::new(0.5));
+        weights.op_name("weights");
+        let (weights, init_weights) = weights.finish().unwrap();
+        let mut labels = Variable::<String>::build(&[2], ConstantInitialiser::new("label".to_string()));
+        labels.op_name("labels");
+        let (labels, init_labels) = labels.finish().unwrap();
+        let assign = ops::Assign::build(labels.clone(), constant(vec!["a".to_string(), "bc".to_string()]));
+        let saver = Saver::new(&mut graph, vec![weights.clone()]).unwrap();
+        let label_saver = Saver::new(&mut graph, vec![labels.clone()]).unwrap();
+        let sess = Session::new(&SessionOptions::new(), &graph).unwrap();
+        {
+            let mut run = SessionRun::new(&mut graph);
+            run.add_op(&init_weights).unwrap();
+            run.add_op(&init_labels).unwrap();
+            run.run(&sess).unwrap();
+        }
+        {
+            let mut run = SessionRun::new(&mut graph);
+            run.add_op(&assign).unwrap();
+            run.run(&sess).unwrap();
+        }
+        saver.save(&sess, &path).unwrap();
+        let label_path = dir.join("labels.ckpt");
+        label_saver.save(&sess, &label_path).unwrap();
+
+        let reader = CheckpointReader::open(&path).unwrap();
+        assert_eq!(reader.names(), vec!["weights"]);
+        assert_eq!(reader.data_type("weights").unwrap(), DataType::Float);
+        assert_eq!(reader.shape("weights").unwrap(), Shape(Some(vec![Some(2), Some(3)])));
+        let weights: Tensor<f32> = reader.tensor("weights").unwrap();
+        assert_eq!(weights.dims(), &[2, 3]);
+        assert_eq!(&weights[..], &[0.5; 6]);
+        assert!(reader.tensor::<f64>("weights").is_err());
+        assert_eq!(reader.tensor::<f32>("missing").unwrap_err().code(), Code::NotFound);
+
+        let reader = CheckpointReader::open(&label_path).unwrap();
+        let labels: Tensor<String> = reader.tensor("labels").unwrap();
+        assert_eq!(&labels[..], &["a".to_string(), "bc".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
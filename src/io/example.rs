@@ -0,0 +1,377 @@
+//! Typed encoding and decoding of `tf.train.Example` and
+//! `tf.train.SequenceExample` protocol buffers.
+//!
+//! These are the records most commonly stored in TFRecords files, e.g.
+//!
+//! ```rust,ignore
+//! let example = Example::new()
+//!     .with_int64s("label", vec![1])
+//!     .with_floats("pixels", vec![0.0, 0.5, 1.0]);
+//! record_writer.write_record(&example.to_bytes()?)?;
+//! ```
+
+use super::super::Result;
+use protobuf::Message;
+use protobuf::RepeatedField;
+use std::collections::HashMap;
+use tensorflow_protos::example as proto_example;
+use tensorflow_protos::feature as proto;
+use tensorflow_protos::feature::Feature_oneof_kind;
+
+/// A single feature: a list of bytes, floats or 64-bit integers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Feature {
+    /// A list of byte strings.
+    Bytes(Vec<Vec<u8>>),
+    /// A list of floats.
+    Float(Vec<f32>),
+    /// A list of 64-bit integers.
+    Int64(Vec<i64>),
+}
+
+impl Feature {
+    /// Returns the values if this is a bytes list.
+    pub fn bytes_list(&self) -> Option<&[Vec<u8>]> {
+        match *self {
+            Feature::Bytes(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a float list.
+    pub fn float_list(&self) -> Option<&[f32]> {
+        match *self {
+            Feature::Float(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is an int64 list.
+    pub fn int64_list(&self) -> Option<&[i64]> {
+        match *self {
+            Feature::Int64(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of values in the list.
+    pub fn len(&self) -> usize {
+        match *self {
+            Feature::Bytes(ref v) => v.len(),
+            Feature::Float(ref v) => v.len(),
+            Feature::Int64(ref v) => v.len(),
+        }
+    }
+
+    /// Returns true if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn to_proto(&self) -> proto::Feature {
+        let mut feature = proto::Feature::new();
+        match *self {
+            Feature::Bytes(ref v) => {
+                let mut list = proto::BytesList::new();
+                list.set_value(RepeatedField::from_vec(v.clone()));
+                feature.set_bytes_list(list);
+            }
+            Feature::Float(ref v) => {
+                let mut list = proto::FloatList::new();
+                list.set_value(v.clone());
+                feature.set_float_list(list);
+            }
+            Feature::Int64(ref v) => {
+                let mut list = proto::Int64List::new();
+                list.set_value(v.clone());
+                feature.set_int64_list(list);
+            }
+        }
+        feature
+    }
+
+    // TensorFlow writes empty features without a list, which are read as an empty bytes list.
+    fn from_proto(mut feature: proto::Feature) -> Self {
+        match feature.kind.take() {
+            Some(Feature_oneof_kind::bytes_list(mut list)) =>
+                Feature::Bytes(list.take_value().into_vec()),
+            Some(Feature_oneof_kind::float_list(mut list)) =>
+                Feature::Float(list.take_value()),
+            Some(Feature_oneof_kind::int64_list(mut list)) =>
+                Feature::Int64(list.take_value()),
+            None => Feature::Bytes(Vec::new()),
+        }
+    }
+}
+
+impl From<Vec<Vec<u8>>> for Feature {
+    fn from(value: Vec<Vec<u8>>) -> Self {
+        Feature::Bytes(value)
+    }
+}
+
+impl From<Vec<f32>> for Feature {
+    fn from(value: Vec<f32>) -> Self {
+        Feature::Float(value)
+    }
+}
+
+impl From<Vec<i64>> for Feature {
+    fn from(value: Vec<i64>) -> Self {
+        Feature::Int64(value)
+    }
+}
+
+fn features_to_proto(features: &HashMap<String, Feature>) -> proto::Features {
+    let mut proto = proto::Features::new();
+    for (name, feature) in features {
+        proto.mut_feature().insert(name.clone(), feature.to_proto());
+    }
+    proto
+}
+
+fn features_from_proto(mut proto: proto::Features) -> HashMap<String, Feature> {
+    proto.take_feature()
+         .into_iter()
+         .map(|(name, feature)| (name, Feature::from_proto(feature)))
+         .collect()
+}
+
+////////////////////////
+
+/// A map from feature names to features, equivalent to `tf.train.Example`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Example {
+    features: HashMap<String, Feature>,
+}
+
+impl Example {
+    /// Creates an empty Example.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a bytes list feature.
+    pub fn with_bytes<S: Into<String>>(mut self, name: S, values: Vec<Vec<u8>>) -> Self {
+        self.insert(name, Feature::Bytes(values));
+        self
+    }
+
+    /// Adds a float list feature.
+    pub fn with_floats<S: Into<String>>(mut self, name: S, values: Vec<f32>) -> Self {
+        self.insert(name, Feature::Float(values));
+        self
+    }
+
+    /// Adds an int64 list feature.
+    pub fn with_int64s<S: Into<String>>(mut self, name: S, values: Vec<i64>) -> Self {
+        self.insert(name, Feature::Int64(values));
+        self
+    }
+
+    /// Adds a feature, returning the previous feature with the same name if
+    /// there was one.
+    pub fn insert<S: Into<String>>(&mut self, name: S, feature: Feature) -> Option<Feature> {
+        self.features.insert(name.into(), feature)
+    }
+
+    /// Returns the feature with the given name.
+    pub fn get(&self, name: &str) -> Option<&Feature> {
+        self.features.get(name)
+    }
+
+    /// Returns the named feature if it is a bytes list.
+    pub fn bytes_list(&self, name: &str) -> Option<&[Vec<u8>]> {
+        self.get(name).and_then(Feature::bytes_list)
+    }
+
+    /// Returns the named feature if it is a float list.
+    pub fn float_list(&self, name: &str) -> Option<&[f32]> {
+        self.get(name).and_then(Feature::float_list)
+    }
+
+    /// Returns the named feature if it is an int64 list.
+    pub fn int64_list(&self, name: &str) -> Option<&[i64]> {
+        self.get(name).and_then(Feature::int64_list)
+    }
+
+    /// Returns all features, keyed by name.
+    pub fn features(&self) -> &HashMap<String, Feature> {
+        &self.features
+    }
+
+    /// Serializes the Example as a `tf.train.Example` protocol buffer.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut proto = proto_example::Example::new();
+        proto.set_features(features_to_proto(&self.features));
+        Ok(proto.write_to_bytes()?)
+    }
+
+    /// Parses a serialized `tf.train.Example` protocol buffer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut proto = proto_example::Example::new();
+        proto.merge_from_bytes(bytes)?;
+        Ok(Example {
+            features: features_from_proto(proto.take_features()),
+        })
+    }
+}
+
+////////////////////////
+
+/// Context features plus named lists of features indexed by time, equivalent
+/// to `tf.train.SequenceExample`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SequenceExample {
+    context: HashMap<String, Feature>,
+    feature_lists: HashMap<String, Vec<Feature>>,
+}
+
+impl SequenceExample {
+    /// Creates an empty SequenceExample.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a context feature.
+    pub fn with_context<S: Into<String>, F: Into<Feature>>(mut self, name: S, feature: F) -> Self {
+        self.context.insert(name.into(), feature.into());
+        self
+    }
+
+    /// Adds a feature list, with one feature per time step.
+    pub fn with_feature_list<S: Into<String>>(mut self, name: S, features: Vec<Feature>) -> Self {
+        self.feature_lists.insert(name.into(), features);
+        self
+    }
+
+    /// Appends a time step to the named feature list, creating the list if
+    /// necessary.
+    pub fn push<F: Into<Feature>>(&mut self, name: &str, feature: F) {
+        self.feature_lists
+            .entry(name.to_string())
+            .or_insert_with(Vec::new)
+            .push(feature.into());
+    }
+
+    /// Returns the context features, keyed by name.
+    pub fn context(&self) -> &HashMap<String, Feature> {
+        &self.context
+    }
+
+    /// Returns the mutable context features, keyed by name.
+    pub fn context_mut(&mut self) -> &mut HashMap<String, Feature> {
+        &mut self.context
+    }
+
+    /// Returns the named feature list.
+    pub fn feature_list(&self, name: &str) -> Option<&[Feature]> {
+        self.feature_lists.get(name).map(|v| v.as_slice())
+    }
+
+    /// Returns all feature lists, keyed by name.
+    pub fn feature_lists(&self) -> &HashMap<String, Vec<Feature>> {
+        &self.feature_lists
+    }
+
+    /// Serializes the SequenceExample as a `tf.train.SequenceExample`
+    /// protocol buffer.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut proto = proto_example::SequenceExample::new();
+        proto.set_context(features_to_proto(&self.context));
+        let mut feature_lists = proto::FeatureLists::new();
+        for (name, features) in &self.feature_lists {
+            let mut list = proto::FeatureList::new();
+            list.set_feature(features.iter().map(Feature::to_proto).collect());
+            feature_lists.mut_feature_list().insert(name.clone(), list);
+        }
+        proto.set_feature_lists(feature_lists);
+        Ok(proto.write_to_bytes()?)
+    }
+
+    /// Parses a serialized `tf.train.SequenceExample` protocol buffer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut proto = proto_example::SequenceExample::new();
+        proto.merge_from_bytes(bytes)?;
+        let mut feature_lists = HashMap::new();
+        for (name, mut list) in proto.mut_feature_lists().take_feature_list() {
+            let features = list.take_feature().into_iter().map(Feature::from_proto).collect();
+            feature_lists.insert(name, features);
+        }
+        Ok(SequenceExample {
+            context: features_from_proto(proto.take_context()),
+            feature_lists,
+        })
+    }
+}
+
+////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{RecordReader, RecordWriter};
+
+    #[test]
+    fn example_round_trip() {
+        let example = Example::new()
+            .with_bytes("name", vec![b"fox".to_vec(), b"dog".to_vec()])
+            .with_floats("weights", vec![0.5, -1.25])
+            .with_int64s("label", vec![7]);
+        let parsed = Example::from_bytes(&example.to_bytes().unwrap()).unwrap();
+        assert_eq!(parsed, example);
+        assert_eq!(parsed.int64_list("label"), Some(&[7i64][..]));
+        assert_eq!(parsed.float_list("label"), None);
+        assert_eq!(parsed.bytes_list("name").unwrap()[1], b"dog".to_vec());
+    }
+
+    #[test]
+    fn example_from_python_bytes() {
+        // tf.train.Example(features=tf.train.Features(feature={
+        //     "a": tf.train.Feature(int64_list=tf.train.Int64List(value=[1]))}))
+        //   .SerializeToString()
+        let bytes = [0x0a, 0x0c, 0x0a, 0x0a, 0x0a, 0x01, 0x61, 0x12, 0x05, 0x1a, 0x03, 0x0a, 0x01, 0x01];
+        let example = Example::from_bytes(&bytes).unwrap();
+        assert_eq!(example, Example::new().with_int64s("a", vec![1]));
+        assert_eq!(example.to_bytes().unwrap(), bytes.to_vec());
+    }
+
+    #[test]
+    fn example_with_empty_feature() {
+        // tf.train.Example(features=tf.train.Features(feature={"a": tf.train.Feature()}))
+        //   .SerializeToString()
+        let bytes = [0x0a, 0x07, 0x0a, 0x05, 0x0a, 0x01, 0x61, 0x12, 0x00];
+        let example = Example::from_bytes(&bytes).unwrap();
+        assert_eq!(example.bytes_list("a"), Some(&[][..]));
+    }
+
+    #[test]
+    fn sequence_example_round_trip() {
+        let mut example = SequenceExample::new()
+            .with_context("length", vec![2i64])
+            .with_feature_list("tokens", vec![Feature::Int64(vec![3, 4]), Feature::Int64(vec![5])]);
+        example.push("scores", vec![0.25f32]);
+        example.push("scores", vec![0.75f32]);
+        let parsed = SequenceExample::from_bytes(&example.to_bytes().unwrap()).unwrap();
+        assert_eq!(parsed, example);
+        assert_eq!(parsed.feature_list("scores").unwrap()[1].float_list(), Some(&[0.75f32][..]));
+    }
+
+    #[test]
+    fn examples_in_records() {
+        let examples: Vec<Example> = (0..3)
+            .map(|i| Example::new().with_int64s("i", vec![i]))
+            .collect();
+        let mut buf = Vec::new();
+        {
+            let mut record_writer = RecordWriter::new(&mut buf);
+            for example in &examples {
+                record_writer.write_record(&example.to_bytes().unwrap()).unwrap();
+            }
+        }
+        let parsed: Vec<Example> = RecordReader::new(&buf[..])
+            .map(|record| Example::from_bytes(&record.unwrap()).unwrap())
+            .collect();
+        assert_eq!(parsed, examples);
+    }
+}
@@ -0,0 +1,1258 @@
+//! Neural network layers built from the generated ops.
+//!
+//! Each layer owns the variables it creates, which are added to the graph the first time the
+//! layer's output is used. Layers which hold variables are created with a builder, e.g.
+//!
+//! ```ignore
+//! let mut hidden = Dense::build(784, 128);
+//! hidden.name("hidden");
+//! hidden.activation(Activation::Relu);
+//! let hidden = hidden.finish()?;
+//! let output = Dense::<f32>::new(128, 10)?;
+//! let logits = output.apply(hidden.apply(images));
+//! ```
+//!
+//! Inputs are laid out with the batch dimension first and, for images, the channels last.
+
+use super::{Edge, RefEdge, GraphEdge, Result, Tensor, TensorType};
+use super::ops::{self, NoOp};
+use super::sugar::{constant, scalar, Float, Initialiser, Variable, ConstantInitialiser, OrthogonalInitialiser,
+                   RandomUniformInitialiser, TensorInitialiser, VarianceScalingInitialiser};
+use std::fmt;
+use std::iter::repeat;
+use std::marker::PhantomData;
+
+/// A layer which owns variables
+pub trait Layer<T: TensorType> {
+    /// Returns all of the layer's variables
+    fn variables(&self) -> Vec<RefEdge<T>>;
+
+    /// Returns the variables which should be updated by training
+    fn trainable_variables(&self) -> Vec<RefEdge<T>>;
+
+    /// Returns an operation which initialises all of the layer's variables
+    fn initializer(&self) -> NoOp;
+}
+
+// The variables created by a layer, with their initialisers and whether they are trainable.
+#[derive(Clone)]
+struct Variables<T: TensorType> {
+    name: Option<String>,
+    variables: Vec<(RefEdge<T>, RefEdge<T>, bool)>,
+}
+
+impl<T: TensorType> Variables<T> {
+    fn new(name: Option<String>) -> Self {
+        Self {
+            name,
+            variables: Vec::new(),
+        }
+    }
+
+    // Creates a variable, named "{layer}/{name}" if the layer has a name.
+    fn add<I: Initialiser<T> + 'static>(&mut self,
+                                       name: &str,
+                                       shape: &[u64],
+                                       initialiser: I,
+                                       trainable: bool) -> Result<RefEdge<T>> {
+        let mut variable = Variable::build(shape, initialiser);
+        if let Some(ref layer) = self.name {
+            variable.op_name(&format!("{}/{}", layer, name));
+        }
+        variable.trainable(trainable);
+        let (variable, init) = variable.finish()?;
+        self.variables.push((variable.clone(), init, trainable));
+        Ok(variable)
+    }
+}
+
+macro_rules! impl_layer {
+    ($name:ident) => {
+        impl<T: TensorType> Layer<T> for $name<T> {
+            fn variables(&self) -> Vec<RefEdge<T>> {
+                self.variables.variables.iter().map(|(variable, _, _)| variable.clone()).collect()
+            }
+
+            fn trainable_variables(&self) -> Vec<RefEdge<T>> {
+                self.variables.variables.iter()
+                    .filter(|(_, _, trainable)| *trainable)
+                    .map(|(variable, _, _)| variable.clone())
+                    .collect()
+            }
+
+            fn initializer(&self) -> NoOp {
+                let mut op = NoOp::build();
+                for (_, init, _) in &self.variables.variables {
+                    op.control_input(init.clone());
+                }
+                op
+            }
+        }
+    };
+}
+
+/// The function applied to the output of a layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Activation {
+    /// Leaves the output unchanged
+    Linear,
+    /// max(x, 0)
+    Relu,
+    /// 1 / (1 + exp(-x))
+    Sigmoid,
+    /// The hyperbolic tangent
+    Tanh,
+    /// Softmax over the last dimension
+    Softmax,
+}
+
+impl Activation {
+    /// Returns the activation of x
+    pub fn apply<T: Float>(self, x: Edge<T>) -> Edge<T> {
+        match self {
+            Activation::Linear => x,
+            Activation::Relu => ops::Relu::new(x),
+            Activation::Sigmoid => ops::Sigmoid::new(x),
+            Activation::Tanh => ops::Tanh::new(x),
+            Activation::Softmax => ops::Softmax::new(x),
+        }
+    }
+}
+
+/// The padding used by convolution and pooling layers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    /// Pads the input so the output has the same size, when the stride is 1
+    Same,
+    /// Only uses windows which fit inside the input
+    Valid,
+}
+
+impl Padding {
+    fn as_str(self) -> &'static str {
+        match self {
+            Padding::Same => "SAME",
+            Padding::Valid => "VALID",
+        }
+    }
+}
+
+////////////////////////
+
+/// A fully connected layer, computing `activation(input * kernel + bias)` for inputs of shape
+/// [batch, input_dim]
+pub struct Dense<T: TensorType> {
+    kernel: RefEdge<T>,
+    bias: Option<RefEdge<T>>,
+    activation: Activation,
+    variables: Variables<T>,
+}
+
+impl<T: TensorType> fmt::Debug for Dense<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dense")
+         .field("name", &self.variables.name)
+         .field("activation", &self.activation)
+         .field("use_bias", &self.bias.is_some())
+         .finish()
+    }
+}
+
+impl<T: Float> Dense<T> {
+    /// Creates a layer with the default options
+    pub fn new(input_dim: u64, units: u64) -> Result<Self> {
+        Self::build(input_dim, units).finish()
+    }
+
+    /// Returns a builder for a layer mapping input_dim inputs to units outputs
+    pub fn build(input_dim: u64, units: u64) -> DenseBuilder<T> {
+        DenseBuilder {
+            input_dim,
+            units,
+            name: None,
+            activation: Activation::Linear,
+            use_bias: true,
+            kernel_initialiser: Box::new(VarianceScalingInitialiser::glorot_uniform(None)),
+            bias_initialiser: Box::new(ConstantInitialiser::new(T::zero())),
+        }
+    }
+
+    /// The weights, of shape [input_dim, units]
+    pub fn kernel(&self) -> &RefEdge<T> {
+        &self.kernel
+    }
+
+    /// The bias, of shape [units], if the layer has one
+    pub fn bias(&self) -> Option<&RefEdge<T>> {
+        self.bias.as_ref()
+    }
+
+    /// Returns the output of the layer for input
+    pub fn apply<X: GraphEdge<T> + Clone + 'static>(&self, input: X) -> Edge<T> {
+        let mut output = ops::MatMul::new(input, self.kernel.clone());
+        if let Some(ref bias) = self.bias {
+            output = ops::BiasAdd::new(output, bias.clone());
+        }
+        self.activation.apply(output)
+    }
+}
+
+impl_layer!(Dense);
+
+/// Sets the options for a `Dense` layer
+pub struct DenseBuilder<T: TensorType> {
+    input_dim: u64,
+    units: u64,
+    name: Option<String>,
+    activation: Activation,
+    use_bias: bool,
+    kernel_initialiser: Box<dyn Initialiser<T>>,
+    bias_initialiser: Box<dyn Initialiser<T>>,
+}
+
+impl<T: TensorType> fmt::Debug for DenseBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DenseBuilder")
+         .field("input_dim", &self.input_dim)
+         .field("units", &self.units)
+         .field("name", &self.name)
+         .field("activation", &self.activation)
+         .field("use_bias", &self.use_bias)
+         .finish()
+    }
+}
+
+impl<T: Float> DenseBuilder<T> {
+    /// Names the variables "{name}/kernel" and "{name}/bias"
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Defaults to `Activation::Linear`
+    pub fn activation(&mut self, activation: Activation) -> &mut Self {
+        self.activation = activation;
+        self
+    }
+
+    /// Defaults to true
+    pub fn use_bias(&mut self, use_bias: bool) -> &mut Self {
+        self.use_bias = use_bias;
+        self
+    }
+
+    /// Defaults to Glorot uniform
+    pub fn kernel_initialiser<I: Initialiser<T> + 'static>(&mut self, initialiser: I) -> &mut Self {
+        self.kernel_initialiser = Box::new(initialiser);
+        self
+    }
+
+    /// Defaults to zeros
+    pub fn bias_initialiser<I: Initialiser<T> + 'static>(&mut self, initialiser: I) -> &mut Self {
+        self.bias_initialiser = Box::new(initialiser);
+        self
+    }
+
+    /// Creates the layer and its variables
+    pub fn finish(self) -> Result<Dense<T>> {
+        let mut variables = Variables::new(self.name);
+        let kernel = variables.add("kernel", &[self.input_dim, self.units], self.kernel_initialiser, true)?;
+        let bias = if self.use_bias {
+            Some(variables.add("bias", &[self.units], self.bias_initialiser, true)?)
+        } else {
+            None
+        };
+        Ok(Dense {
+            kernel,
+            bias,
+            activation: self.activation,
+            variables,
+        })
+    }
+}
+
+////////////////////////
+
+/// A 2D convolution layer for inputs of shape [batch, height, width, channels]
+pub struct Conv2D<T: TensorType> {
+    kernel: RefEdge<T>,
+    bias: Option<RefEdge<T>>,
+    strides: [i64; 2],
+    padding: Padding,
+    activation: Activation,
+    variables: Variables<T>,
+}
+
+impl<T: TensorType> fmt::Debug for Conv2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Conv2D")
+         .field("name", &self.variables.name)
+         .field("strides", &self.strides)
+         .field("padding", &self.padding)
+         .field("activation", &self.activation)
+         .field("use_bias", &self.bias.is_some())
+         .finish()
+    }
+}
+
+impl<T: Float> Conv2D<T> {
+    /// Creates a layer with filters outputs, each computed from a window of kernel_size
+    /// [height, width] over all of the input channels
+    pub fn new(kernel_size: [u64; 2], in_channels: u64, filters: u64) -> Result<Self> {
+        Self::build(kernel_size, in_channels, filters).finish()
+    }
+
+    /// Returns a builder for the layer, with the same arguments as `new`
+    pub fn build(kernel_size: [u64; 2], in_channels: u64, filters: u64) -> Conv2DBuilder<T> {
+        Conv2DBuilder {
+            kernel_size,
+            in_channels,
+            filters,
+            strides: [1, 1],
+            padding: Padding::Valid,
+            name: None,
+            activation: Activation::Linear,
+            use_bias: true,
+            kernel_initialiser: Box::new(VarianceScalingInitialiser::glorot_uniform(None)),
+            bias_initialiser: Box::new(ConstantInitialiser::new(T::zero())),
+        }
+    }
+
+    /// The filters, of shape [height, width, in_channels, filters]
+    pub fn kernel(&self) -> &RefEdge<T> {
+        &self.kernel
+    }
+
+    /// The bias, of shape [filters], if the layer has one
+    pub fn bias(&self) -> Option<&RefEdge<T>> {
+        self.bias.as_ref()
+    }
+
+    /// Returns the output of the layer for input
+    pub fn apply<X: GraphEdge<T> + Clone + 'static>(&self, input: X) -> Edge<T> {
+        let strides = [1, self.strides[0], self.strides[1], 1];
+        let mut output = ops::Conv2D::new(input, self.kernel.clone(), &strides, self.padding.as_str());
+        if let Some(ref bias) = self.bias {
+            output = ops::BiasAdd::new(output, bias.clone());
+        }
+        self.activation.apply(output)
+    }
+}
+
+impl_layer!(Conv2D);
+
+/// Sets the options for a `Conv2D` layer
+pub struct Conv2DBuilder<T: TensorType> {
+    kernel_size: [u64; 2],
+    in_channels: u64,
+    filters: u64,
+    strides: [i64; 2],
+    padding: Padding,
+    name: Option<String>,
+    activation: Activation,
+    use_bias: bool,
+    kernel_initialiser: Box<dyn Initialiser<T>>,
+    bias_initialiser: Box<dyn Initialiser<T>>,
+}
+
+impl<T: TensorType> fmt::Debug for Conv2DBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Conv2DBuilder")
+         .field("kernel_size", &self.kernel_size)
+         .field("in_channels", &self.in_channels)
+         .field("filters", &self.filters)
+         .field("strides", &self.strides)
+         .field("padding", &self.padding)
+         .field("name", &self.name)
+         .field("activation", &self.activation)
+         .field("use_bias", &self.use_bias)
+         .finish()
+    }
+}
+
+impl<T: Float> Conv2DBuilder<T> {
+    /// Names the variables "{name}/kernel" and "{name}/bias"
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The [height, width] step between windows. Defaults to [1, 1].
+    pub fn strides(&mut self, strides: [i64; 2]) -> &mut Self {
+        self.strides = strides;
+        self
+    }
+
+    /// Defaults to `Padding::Valid`
+    pub fn padding(&mut self, padding: Padding) -> &mut Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Defaults to `Activation::Linear`
+    pub fn activation(&mut self, activation: Activation) -> &mut Self {
+        self.activation = activation;
+        self
+    }
+
+    /// Defaults to true
+    pub fn use_bias(&mut self, use_bias: bool) -> &mut Self {
+        self.use_bias = use_bias;
+        self
+    }
+
+    /// Defaults to Glorot uniform
+    pub fn kernel_initialiser<I: Initialiser<T> + 'static>(&mut self, initialiser: I) -> &mut Self {
+        self.kernel_initialiser = Box::new(initialiser);
+        self
+    }
+
+    /// Defaults to zeros
+    pub fn bias_initialiser<I: Initialiser<T> + 'static>(&mut self, initialiser: I) -> &mut Self {
+        self.bias_initialiser = Box::new(initialiser);
+        self
+    }
+
+    /// Creates the layer and its variables
+    pub fn finish(self) -> Result<Conv2D<T>> {
+        let mut variables = Variables::new(self.name);
+        let shape = [self.kernel_size[0], self.kernel_size[1], self.in_channels, self.filters];
+        let kernel = variables.add("kernel", &shape, self.kernel_initialiser, true)?;
+        let bias = if self.use_bias {
+            Some(variables.add("bias", &[self.filters], self.bias_initialiser, true)?)
+        } else {
+            None
+        };
+        Ok(Conv2D {
+            kernel,
+            bias,
+            strides: self.strides,
+            padding: self.padding,
+            activation: self.activation,
+            variables,
+        })
+    }
+}
+
+////////////////////////
+
+/// Takes the maximum over windows of inputs of shape [batch, height, width, channels]
+#[derive(Debug, Clone, Copy)]
+pub struct MaxPool2D {
+    pool_size: [i64; 2],
+    strides: [i64; 2],
+    padding: Padding,
+}
+
+/// Takes the average over windows of inputs of shape [batch, height, width, channels]
+#[derive(Debug, Clone, Copy)]
+pub struct AvgPool2D {
+    pool_size: [i64; 2],
+    strides: [i64; 2],
+    padding: Padding,
+}
+
+macro_rules! impl_pool {
+    ($name:ident, $op:ident) => {
+        impl $name {
+            /// Creates a layer pooling windows of [height, width], with strides equal to the
+            /// window size and `Padding::Valid`
+            pub fn new(pool_size: [i64; 2]) -> Self {
+                Self {
+                    pool_size,
+                    strides: pool_size,
+                    padding: Padding::Valid,
+                }
+            }
+
+            /// The [height, width] step between windows
+            pub fn strides(&mut self, strides: [i64; 2]) -> &mut Self {
+                self.strides = strides;
+                self
+            }
+
+            /// Defaults to `Padding::Valid`
+            pub fn padding(&mut self, padding: Padding) -> &mut Self {
+                self.padding = padding;
+                self
+            }
+
+            /// Returns the pooled input
+            pub fn apply<T: Float, X: GraphEdge<T> + Clone + 'static>(&self, input: X) -> Edge<T> {
+                let ksize = [1, self.pool_size[0], self.pool_size[1], 1];
+                let strides = [1, self.strides[0], self.strides[1], 1];
+                ops::$op::new(input, &ksize, &strides, self.padding.as_str())
+            }
+        }
+
+        impl<T: TensorType> Layer<T> for $name {
+            fn variables(&self) -> Vec<RefEdge<T>> {
+                Vec::new()
+            }
+
+            fn trainable_variables(&self) -> Vec<RefEdge<T>> {
+                Vec::new()
+            }
+
+            fn initializer(&self) -> NoOp {
+                NoOp::build()
+            }
+        }
+    };
+}
+
+impl_pool!(MaxPool2D, MaxPool);
+impl_pool!(AvgPool2D, AvgPool);
+
+////////////////////////
+
+/// Normalises its input over every dimension but the last, then scales and shifts it.
+///
+/// During training the statistics of the batch are used and the moving averages are updated;
+/// otherwise the moving averages are used.
+pub struct BatchNorm<T: TensorType> {
+    gamma: RefEdge<T>,
+    beta: RefEdge<T>,
+    moving_mean: RefEdge<T>,
+    moving_variance: RefEdge<T>,
+    momentum: f64,
+    epsilon: f64,
+    variables: Variables<T>,
+}
+
+impl<T: TensorType> fmt::Debug for BatchNorm<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BatchNorm")
+         .field("name", &self.variables.name)
+         .field("momentum", &self.momentum)
+         .field("epsilon", &self.epsilon)
+         .finish()
+    }
+}
+
+impl<T: Float> BatchNorm<T> {
+    /// Creates a layer with the default options for inputs with channels elements in the last
+    /// dimension
+    pub fn new(channels: u64) -> Result<Self> {
+        Self::build(channels).finish()
+    }
+
+    /// Returns a builder for the layer, with the same arguments as `new`
+    pub fn build(channels: u64) -> BatchNormBuilder<T> {
+        BatchNormBuilder {
+            channels,
+            name: None,
+            momentum: 0.99,
+            epsilon: 1e-3,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The average of the batch means seen during training
+    pub fn moving_mean(&self) -> &RefEdge<T> {
+        &self.moving_mean
+    }
+
+    /// The average of the batch variances seen during training
+    pub fn moving_variance(&self) -> &RefEdge<T> {
+        &self.moving_variance
+    }
+
+    /// Returns the normalised input. When training, the moving averages are updated whenever
+    /// the output is computed.
+    pub fn apply<X: GraphEdge<T> + Clone + 'static>(&self, input: X, training: bool) -> Edge<T> {
+        let input = ops::Identity::new(input);
+        if !training {
+            return self.normalize(input, self.moving_mean.clone(), self.moving_variance.clone());
+        }
+        let rank = ops::Rank::new(input.clone());
+        let axes = ops::Range::new(constant(0i32), rank - constant(1i32), constant(1i32));
+        let mean = ops::Mean::new(input.clone(), axes.clone());
+        let variance = ops::Mean::new(ops::Square::new(&input - &mean), axes);
+        let output = self.normalize(input, mean.clone(), variance.clone());
+
+        let mut output = ops::Identity::build(output);
+        output.control_input(self.update(&self.moving_mean, mean));
+        output.control_input(self.update(&self.moving_variance, variance));
+        output.finish()
+    }
+
+    fn normalize<M, V>(&self, input: Edge<T>, mean: M, variance: V) -> Edge<T>
+    where M: GraphEdge<T> + Clone + 'static,
+          V: GraphEdge<T> + Clone + 'static, {
+        let inv = ops::Rsqrt::new(ops::Add::new(variance, scalar::<T>(self.epsilon)));
+        let scale = ops::Mul::new(inv, self.gamma.clone());
+        ops::Add::new((input - ops::Identity::new(mean)) * scale, self.beta.clone())
+    }
+
+    // Moves average towards value by 1 - momentum.
+    fn update(&self, average: &RefEdge<T>, value: Edge<T>) -> RefEdge<T> {
+        let decayed = ops::Mul::new(average.clone(), scalar::<T>(self.momentum));
+        ops::Assign::new(average.clone(), decayed + value * scalar(1.0 - self.momentum))
+    }
+}
+
+impl_layer!(BatchNorm);
+
+/// Sets the options for a `BatchNorm` layer
+pub struct BatchNormBuilder<T: TensorType> {
+    channels: u64,
+    name: Option<String>,
+    momentum: f64,
+    epsilon: f64,
+    phantom: PhantomData<T>,
+}
+
+impl<T: TensorType> fmt::Debug for BatchNormBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BatchNormBuilder")
+         .field("channels", &self.channels)
+         .field("name", &self.name)
+         .field("momentum", &self.momentum)
+         .field("epsilon", &self.epsilon)
+         .finish()
+    }
+}
+
+impl<T: Float> BatchNormBuilder<T> {
+    /// Names the variables "{name}/gamma", "{name}/beta", "{name}/moving_mean" and
+    /// "{name}/moving_variance"
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// How much of the moving averages is kept at each training step. Defaults to 0.99.
+    pub fn momentum(&mut self, momentum: f64) -> &mut Self {
+        self.momentum = momentum;
+        self
+    }
+
+    /// Added to the variance to avoid dividing by zero. Defaults to 0.001.
+    pub fn epsilon(&mut self, epsilon: f64) -> &mut Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Creates the layer and its variables
+    pub fn finish(self) -> Result<BatchNorm<T>> {
+        let shape = [self.channels];
+        let mut variables = Variables::new(self.name);
+        let gamma = variables.add("gamma", &shape, ConstantInitialiser::new(T::one()), true)?;
+        let beta = variables.add("beta", &shape, ConstantInitialiser::new(T::zero()), true)?;
+        let moving_mean = variables.add("moving_mean", &shape, ConstantInitialiser::new(T::zero()), false)?;
+        let moving_variance = variables.add("moving_variance", &shape, ConstantInitialiser::new(T::one()), false)?;
+        Ok(BatchNorm {
+            gamma,
+            beta,
+            moving_mean,
+            moving_variance,
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+            variables,
+        })
+    }
+}
+
+////////////////////////
+
+/// Randomly sets a fraction of its inputs to zero during training, scaling the rest up so the
+/// expected sum is unchanged
+#[derive(Debug, Clone, Copy)]
+pub struct Dropout {
+    rate: f64,
+    seed: Option<i64>,
+}
+
+impl Dropout {
+    /// rate is the fraction of inputs to drop, and must be in [0, 1)
+    pub fn new(rate: f64, seed: Option<i64>) -> Result<Self> {
+        if !(0.0..1.0).contains(&rate) {
+            return Err(invalid_arg!("Dropout rate must be in [0, 1), got {}", rate));
+        }
+        Ok(Self {
+            rate,
+            seed,
+        })
+    }
+
+    /// Returns input with dropout applied when training, and unchanged otherwise
+    pub fn apply<T: Float, X: GraphEdge<T> + Clone + 'static>(&self, input: X, training: bool) -> Edge<T> {
+        let input = ops::Identity::new(input);
+        if !training || self.rate == 0.0 {
+            return input;
+        }
+        let shape = ops::Shape::<T, Edge<T>, i64>::new(input.clone());
+        let mut uniform = ops::RandomUniform::<i64, Edge<i64>, T>::build(shape);
+        if let Some(seed) = self.seed {
+            uniform = uniform.seed(seed);
+        }
+        let keep = ops::GreaterEqual::new(uniform.finish(), scalar::<T>(self.rate));
+        input * ops::Cast::<bool, Edge<bool>, T>::new(keep) * scalar(1.0 / (1.0 - self.rate))
+    }
+}
+
+impl<T: TensorType> Layer<T> for Dropout {
+    fn variables(&self) -> Vec<RefEdge<T>> {
+        Vec::new()
+    }
+
+    fn trainable_variables(&self) -> Vec<RefEdge<T>> {
+        Vec::new()
+    }
+
+    fn initializer(&self) -> NoOp {
+        NoOp::build()
+    }
+}
+
+////////////////////////
+
+/// Maps integer ids to dense vectors
+pub struct Embedding<T: TensorType> {
+    embeddings: RefEdge<T>,
+    variables: Variables<T>,
+}
+
+impl<T: TensorType> fmt::Debug for Embedding<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Embedding")
+         .field("name", &self.variables.name)
+         .finish()
+    }
+}
+
+impl<T: Float> Embedding<T> {
+    /// Creates a layer with the default options, mapping ids in [0, vocab_size) to vectors of
+    /// length dim
+    pub fn new(vocab_size: u64, dim: u64) -> Result<Self> {
+        Self::build(vocab_size, dim).finish()
+    }
+
+    /// Returns a builder for the layer, with the same arguments as `new`
+    pub fn build(vocab_size: u64, dim: u64) -> EmbeddingBuilder<T> {
+        EmbeddingBuilder {
+            vocab_size,
+            dim,
+            name: None,
+            initialiser: Box::new(RandomUniformInitialiser::new(-0.05, 0.05, None)),
+        }
+    }
+
+    /// The vectors, of shape [vocab_size, dim]
+    pub fn embeddings(&self) -> &RefEdge<T> {
+        &self.embeddings
+    }
+
+    /// Returns the vectors for ids, with shape ids.shape + [dim]
+    pub fn apply<I, X>(&self, ids: X) -> Edge<T>
+    where I: ops::con_or_DT_INT32_or_DT_INT64 + TensorType,
+          X: GraphEdge<I> + Clone + 'static, {
+        ops::GatherV2::new(self.embeddings.clone(), ids, constant(0i32))
+    }
+}
+
+impl_layer!(Embedding);
+
+/// Sets the options for an `Embedding` layer
+pub struct EmbeddingBuilder<T: TensorType> {
+    vocab_size: u64,
+    dim: u64,
+    name: Option<String>,
+    initialiser: Box<dyn Initialiser<T>>,
+}
+
+impl<T: TensorType> fmt::Debug for EmbeddingBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EmbeddingBuilder")
+         .field("vocab_size", &self.vocab_size)
+         .field("dim", &self.dim)
+         .field("name", &self.name)
+         .finish()
+    }
+}
+
+impl<T: Float> EmbeddingBuilder<T> {
+    /// Names the variable "{name}/embeddings"
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Defaults to uniform over [-0.05, 0.05)
+    pub fn initialiser<I: Initialiser<T> + 'static>(&mut self, initialiser: I) -> &mut Self {
+        self.initialiser = Box::new(initialiser);
+        self
+    }
+
+    /// Creates the layer and its variables
+    pub fn finish(self) -> Result<Embedding<T>> {
+        let mut variables = Variables::new(self.name);
+        let embeddings = variables.add("embeddings", &[self.vocab_size, self.dim], self.initialiser, true)?;
+        Ok(Embedding {
+            embeddings,
+            variables,
+        })
+    }
+}
+
+////////////////////////
+
+// Returns a [batch_size, units] tensor of zeros.
+fn zeros<T: TensorType>(batch_size: u64, units: u64) -> Result<Edge<T>> {
+    Ok(constant(Tensor::from_values(&[batch_size, units], repeat(T::zero()))?))
+}
+
+/// The state of an `LSTMCell`
+#[derive(Clone)]
+pub struct LSTMState<T: TensorType> {
+    /// The cell state, of shape [batch, units]
+    pub c: Edge<T>,
+    /// The output, of shape [batch, units]
+    pub h: Edge<T>,
+}
+
+impl<T: TensorType> fmt::Debug for LSTMState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LSTMState")
+         .field("data_type", &T::data_type())
+         .finish()
+    }
+}
+
+/// A long short-term memory cell, computing one time step for inputs of shape
+/// [batch, input_dim]
+pub struct LSTMCell<T: TensorType> {
+    kernel: RefEdge<T>,
+    recurrent_kernel: RefEdge<T>,
+    bias: RefEdge<T>,
+    units: u64,
+    variables: Variables<T>,
+}
+
+impl<T: TensorType> fmt::Debug for LSTMCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LSTMCell")
+         .field("name", &self.variables.name)
+         .field("units", &self.units)
+         .finish()
+    }
+}
+
+impl<T: Float> LSTMCell<T> {
+    /// Creates a cell with the default options and a state of units elements per batch entry
+    pub fn new(input_dim: u64, units: u64) -> Result<Self> {
+        Self::build(input_dim, units).finish()
+    }
+
+    /// Returns a builder for the cell, with the same arguments as `new`
+    pub fn build(input_dim: u64, units: u64) -> LSTMCellBuilder<T> {
+        LSTMCellBuilder {
+            input_dim,
+            units,
+            name: None,
+            seed: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the all zero state for batch_size inputs
+    pub fn zero_state(&self, batch_size: u64) -> Result<LSTMState<T>> {
+        Ok(LSTMState {
+            c: zeros(batch_size, self.units)?,
+            h: zeros(batch_size, self.units)?,
+        })
+    }
+
+    /// Returns the state after input. The output of the cell is the new state's h.
+    pub fn apply<X: GraphEdge<T> + Clone + 'static>(&self, input: X, state: &LSTMState<T>) -> LSTMState<T> {
+        let z = ops::MatMul::new(input, self.kernel.clone())
+            + ops::MatMul::new(state.h.clone(), self.recurrent_kernel.clone());
+        let z = ops::BiasAdd::new(z, self.bias.clone());
+        let gates = ops::Split::new(constant(1i32), z, 4);
+        let i = ops::Sigmoid::new(gates[0].clone());
+        let f = ops::Sigmoid::new(gates[1].clone());
+        let g = ops::Tanh::new(gates[2].clone());
+        let o = ops::Sigmoid::new(gates[3].clone());
+        let c = f * &state.c + i * g;
+        let h = o * ops::Tanh::new(c.clone());
+        LSTMState {
+            c,
+            h,
+        }
+    }
+}
+
+impl_layer!(LSTMCell);
+
+/// Sets the options for an `LSTMCell`
+pub struct LSTMCellBuilder<T: TensorType> {
+    input_dim: u64,
+    units: u64,
+    name: Option<String>,
+    seed: Option<i64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: TensorType> fmt::Debug for LSTMCellBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LSTMCellBuilder")
+         .field("input_dim", &self.input_dim)
+         .field("units", &self.units)
+         .field("name", &self.name)
+         .field("seed", &self.seed)
+         .finish()
+    }
+}
+
+impl<T: Float> LSTMCellBuilder<T> {
+    /// Names the variables "{name}/kernel", "{name}/recurrent_kernel" and "{name}/bias"
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Seeds the Glorot uniform kernel and orthogonal recurrent kernel initialisers
+    pub fn seed(&mut self, seed: i64) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Creates the cell and its variables. The bias is initialised to one for the forget gate
+    /// and zero elsewhere.
+    pub fn finish(self) -> Result<LSTMCell<T>> {
+        let units = self.units;
+        let mut variables = Variables::new(self.name);
+        let kernel = variables.add("kernel",
+                                   &[self.input_dim, 4 * units],
+                                   VarianceScalingInitialiser::glorot_uniform(self.seed),
+                                   true)?;
+        let recurrent_kernel = variables.add("recurrent_kernel",
+                                             &[units, 4 * units],
+                                             OrthogonalInitialiser::new(1.0, self.seed),
+                                             true)?;
+        // The gates are in the order input, forget, cell, output.
+        let bias = (0..4 * units).map(|i| if i / units == 1 { T::one() } else { T::zero() });
+        let bias = TensorInitialiser::new(Tensor::from_values(&[4 * units], bias)?);
+        let bias = variables.add("bias", &[4 * units], bias, true)?;
+        Ok(LSTMCell {
+            kernel,
+            recurrent_kernel,
+            bias,
+            units,
+            variables,
+        })
+    }
+}
+
+////////////////////////
+
+/// A gated recurrent unit cell, computing one time step for inputs of shape [batch, input_dim]
+///
+/// The reset gate is applied after the recurrent kernel, as in cuDNN. The state is the output,
+/// of shape [batch, units].
+pub struct GRUCell<T: TensorType> {
+    kernel: RefEdge<T>,
+    recurrent_kernel: RefEdge<T>,
+    bias: RefEdge<T>,
+    recurrent_bias: RefEdge<T>,
+    units: u64,
+    variables: Variables<T>,
+}
+
+impl<T: TensorType> fmt::Debug for GRUCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GRUCell")
+         .field("name", &self.variables.name)
+         .field("units", &self.units)
+         .finish()
+    }
+}
+
+impl<T: Float> GRUCell<T> {
+    /// Creates a cell with the default options and a state of units elements per batch entry
+    pub fn new(input_dim: u64, units: u64) -> Result<Self> {
+        Self::build(input_dim, units).finish()
+    }
+
+    /// Returns a builder for the cell, with the same arguments as `new`
+    pub fn build(input_dim: u64, units: u64) -> GRUCellBuilder<T> {
+        GRUCellBuilder {
+            input_dim,
+            units,
+            name: None,
+            seed: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the all zero state for batch_size inputs
+    pub fn zero_state(&self, batch_size: u64) -> Result<Edge<T>> {
+        zeros(batch_size, self.units)
+    }
+
+    /// Returns the state after input, which is also the output of the cell
+    pub fn apply<X: GraphEdge<T> + Clone + 'static>(&self, input: X, state: &Edge<T>) -> Edge<T> {
+        let x = ops::BiasAdd::new(ops::MatMul::new(input, self.kernel.clone()), self.bias.clone());
+        let h = ops::MatMul::new(state.clone(), self.recurrent_kernel.clone());
+        let h = ops::BiasAdd::new(h, self.recurrent_bias.clone());
+        let x = ops::Split::new(constant(1i32), x, 3);
+        let h = ops::Split::new(constant(1i32), h, 3);
+        let z = ops::Sigmoid::new(&x[0] + &h[0]);
+        let r = ops::Sigmoid::new(&x[1] + &h[1]);
+        let candidate = ops::Tanh::new(&x[2] + r * &h[2]);
+        &z * state + (scalar::<T>(1.0) - z) * candidate
+    }
+}
+
+impl_layer!(GRUCell);
+
+/// Sets the options for a `GRUCell`
+pub struct GRUCellBuilder<T: TensorType> {
+    input_dim: u64,
+    units: u64,
+    name: Option<String>,
+    seed: Option<i64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: TensorType> fmt::Debug for GRUCellBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GRUCellBuilder")
+         .field("input_dim", &self.input_dim)
+         .field("units", &self.units)
+         .field("name", &self.name)
+         .field("seed", &self.seed)
+         .finish()
+    }
+}
+
+impl<T: Float> GRUCellBuilder<T> {
+    /// Names the variables "{name}/kernel", "{name}/recurrent_kernel", "{name}/bias" and
+    /// "{name}/recurrent_bias"
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Seeds the Glorot uniform kernel and orthogonal recurrent kernel initialisers
+    pub fn seed(&mut self, seed: i64) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Creates the cell and its variables. The biases are initialised to zero.
+    pub fn finish(self) -> Result<GRUCell<T>> {
+        let units = self.units;
+        let mut variables = Variables::new(self.name);
+        let kernel = variables.add("kernel",
+                                   &[self.input_dim, 3 * units],
+                                   VarianceScalingInitialiser::glorot_uniform(self.seed),
+                                   true)?;
+        let recurrent_kernel = variables.add("recurrent_kernel",
+                                             &[units, 3 * units],
+                                             OrthogonalInitialiser::new(1.0, self.seed),
+                                             true)?;
+        let bias = variables.add("bias", &[3 * units], ConstantInitialiser::new(T::zero()), true)?;
+        let recurrent_bias = variables.add("recurrent_bias", &[3 * units], ConstantInitialiser::new(T::zero()), true)?;
+        Ok(GRUCell {
+            kernel,
+            recurrent_kernel,
+            bias,
+            recurrent_bias,
+            units,
+            variables,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Graph, Session, SessionOptions, SessionRun};
+
+    fn initialise<L: Layer<f32>>(graph: &mut Graph, sess: &Session, layer: &L) {
+        let mut run = SessionRun::new(graph);
+        run.add_op(&layer.initializer()).unwrap();
+        run.run(sess).unwrap();
+    }
+
+    #[test]
+    fn test_dense() {
+        let mut graph = Graph::new();
+
+        let mut dense = Dense::<f32>::build(2, 2);
+        dense.name("dense");
+        dense.activation(Activation::Relu);
+        dense.kernel_initialiser(TensorInitialiser::new(Tensor::new(&[2, 2]).with_values(&[1.0, -1.0, 2.0, -2.0]).unwrap()));
+        dense.bias_initialiser(ConstantInitialiser::new(0.5));
+        let dense = dense.finish().unwrap();
+        let output = dense.apply(constant(Tensor::new(&[1, 2]).with_values(&[1.0f32, 2.0]).unwrap()));
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        output.operation(&mut graph).unwrap();
+        initialise(&mut graph, &sess, &dense);
+        assert_eq!(&sess.fetch(&mut graph, &output).unwrap()[..], &[5.5, 0.0]);
+        assert_eq!(dense.trainable_variables().len(), 2);
+        assert_eq!(graph.trainable_variables().len(), 2);
+        assert_eq!(graph.trainable_variables()[0].name().unwrap(), "dense/kernel");
+    }
+
+    #[test]
+    fn test_conv2d() {
+        let mut graph = Graph::new();
+
+        let mut conv = Conv2D::<f32>::build([2, 2], 1, 1);
+        conv.kernel_initialiser(ConstantInitialiser::new(1.0));
+        conv.use_bias(false);
+        let conv = conv.finish().unwrap();
+        let input = Tensor::new(&[1, 3, 3, 1]).with_values(&[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]).unwrap();
+        let output = conv.apply(constant(input));
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        output.operation(&mut graph).unwrap();
+        initialise(&mut graph, &sess, &conv);
+        let output = sess.fetch(&mut graph, &output).unwrap();
+        assert_eq!(output.dims(), &[1, 2, 2, 1]);
+        assert_eq!(&output[..], &[12.0, 16.0, 24.0, 28.0]);
+        assert!(conv.bias().is_none());
+        assert_eq!(conv.trainable_variables().len(), 1);
+    }
+
+    #[test]
+    fn test_pool2d() {
+        let mut graph = Graph::new();
+
+        let input = constant(Tensor::new(&[1, 2, 4, 1]).with_values(&[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap());
+        let max = MaxPool2D::new([2, 2]).apply(input.clone());
+        let mut avg = AvgPool2D::new([2, 2]);
+        avg.strides([1, 1]);
+        let avg = avg.apply(input);
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        let max = sess.fetch(&mut graph, &max).unwrap();
+        assert_eq!(max.dims(), &[1, 1, 2, 1]);
+        assert_eq!(&max[..], &[6.0, 8.0]);
+        let avg = sess.fetch(&mut graph, &avg).unwrap();
+        assert_eq!(avg.dims(), &[1, 1, 3, 1]);
+        assert_eq!(&avg[..], &[3.5, 4.5, 5.5]);
+    }
+
+    #[test]
+    fn test_dropout() {
+        assert!(Dropout::new(1.0, None).is_err());
+        assert!(Dropout::new(-0.1, None).is_err());
+
+        let mut graph = Graph::new();
+
+        let dropout = Dropout::new(0.5, Some(1)).unwrap();
+        let input = constant(Tensor::new(&[100]).with_values(&[3.0f32; 100]).unwrap());
+        let training = dropout.apply(input.clone(), true);
+        let inference = dropout.apply(input, false);
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        let training = sess.fetch(&mut graph, &training).unwrap();
+        // The kept inputs are scaled by 1 / (1 - rate).
+        assert!(training.iter().all(|x| *x == 0.0 || *x == 6.0));
+        assert!(training.contains(&0.0));
+        assert!(training.contains(&6.0));
+        assert_eq!(&sess.fetch(&mut graph, &inference).unwrap()[..], &[3.0; 100][..]);
+    }
+
+    #[test]
+    fn test_embedding() {
+        let mut graph = Graph::new();
+
+        let mut embedding = Embedding::<f32>::build(3, 2);
+        embedding.name("embedding");
+        embedding.initialiser(TensorInitialiser::new(Tensor::new(&[3, 2]).with_values(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]).unwrap()));
+        let embedding = embedding.finish().unwrap();
+        let output = embedding.apply(constant(vec![2i32, 0, 2]));
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        output.operation(&mut graph).unwrap();
+        initialise(&mut graph, &sess, &embedding);
+        let output = sess.fetch(&mut graph, &output).unwrap();
+        assert_eq!(output.dims(), &[3, 2]);
+        assert_eq!(&output[..], &[4.0, 5.0, 0.0, 1.0, 4.0, 5.0]);
+        assert_eq!(graph.trainable_variables()[0].name().unwrap(), "embedding/embeddings");
+    }
+
+    #[test]
+    fn test_batch_norm() {
+        let mut graph = Graph::new();
+
+        let mut batch_norm = BatchNorm::<f32>::build(1);
+        batch_norm.momentum(0.5);
+        batch_norm.epsilon(0.0);
+        let batch_norm = batch_norm.finish().unwrap();
+        let input = constant(Tensor::new(&[2, 1]).with_values(&[1.0f32, 3.0]).unwrap());
+        let output = batch_norm.apply(input, true);
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        output.operation(&mut graph).unwrap();
+        initialise(&mut graph, &sess, &batch_norm);
+        assert_eq!(&sess.fetch(&mut graph, &output).unwrap()[..], &[-1.0, 1.0]);
+        assert_eq!(&sess.fetch(&mut graph, batch_norm.moving_mean()).unwrap()[..], &[1.0]);
+        assert_eq!(&sess.fetch(&mut graph, batch_norm.moving_variance()).unwrap()[..], &[1.0]);
+        assert_eq!(batch_norm.variables().len(), 4);
+        assert_eq!(batch_norm.trainable_variables().len(), 2);
+    }
+
+    #[test]
+    fn test_lstm_cell() {
+        let mut graph = Graph::new();
+
+        let cell = LSTMCell::<f32>::new(1, 1).unwrap();
+        let state = LSTMState {
+            c: constant(Tensor::new(&[1, 1]).with_values(&[1.0f32]).unwrap()),
+            h: constant(Tensor::new(&[1, 1]).with_values(&[0.0f32]).unwrap()),
+        };
+        let state = cell.apply(cell.zero_state(1).unwrap().h, &state);
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        state.h.operation(&mut graph).unwrap();
+        initialise(&mut graph, &sess, &cell);
+        // With zero inputs only the bias contributes, which opens the forget gate.
+        let forget = 1.0 / (1.0 + (-1.0f32).exp());
+        let c = sess.fetch(&mut graph, &state.c).unwrap()[0];
+        let h = sess.fetch(&mut graph, &state.h).unwrap()[0];
+        assert!((c - forget).abs() < 1e-6);
+        assert!((h - 0.5 * forget.tanh()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gru_cell() {
+        let mut graph = Graph::new();
+
+        let mut cell = GRUCell::<f32>::build(1, 1);
+        cell.seed(1);
+        let cell = cell.finish().unwrap();
+        let input = constant(Tensor::new(&[1, 1]).with_values(&[1.0f32]).unwrap());
+        let state = constant(Tensor::new(&[1, 1]).with_values(&[0.5f32]).unwrap());
+        let output = cell.apply(input, &state);
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        output.operation(&mut graph).unwrap();
+        initialise(&mut graph, &sess, &cell);
+        assert_eq!(&sess.fetch(&mut graph, &cell.zero_state(2).unwrap()).unwrap()[..], &[0.0, 0.0]);
+        // The biases are zero, so the output only depends on the kernels.
+        let k = sess.fetch(&mut graph, &cell.kernel).unwrap();
+        let u = sess.fetch(&mut graph, &cell.recurrent_kernel).unwrap();
+        let sigmoid = |x: f32| 1.0 / (1.0 + (-x).exp());
+        let z = sigmoid(k[0] + 0.5 * u[0]);
+        let r = sigmoid(k[1] + 0.5 * u[1]);
+        let candidate = (k[2] + r * 0.5 * u[2]).tanh();
+        let expected = z * 0.5 + (1.0 - z) * candidate;
+        assert!((sess.fetch(&mut graph, &output).unwrap()[0] - expected).abs() < 1e-6);
+        assert_eq!(cell.trainable_variables().len(), 4);
+    }
+}
@@ -15,6 +15,9 @@
 
 use libc::{c_int, c_uint};
 use num_complex::Complex;
+use protobuf::Message;
+use protobuf::ProtobufEnum;
+use protobuf::ProtobufError;
 use std::cell::Cell;
 use std::cell::RefCell;
 use std::cmp::Ordering;
@@ -42,6 +45,10 @@ use std::rc::Rc;
 
 
 use tensorflow_sys as tf;
+use tensorflow_protos::resource_handle::ResourceHandleProto;
+use tensorflow_protos::tensor_shape::TensorShapeProto;
+use tensorflow_protos::types::DataType as ProtoDataType;
+use tensorflow_protos::tensor_shape::TensorShapeProto_Dim;
 
 #[macro_use]
 use lazy_static::lazy_static;
@@ -189,10 +196,21 @@ pub use crate::graph::*;
 mod session;
 pub use crate::session::*;
 
+mod saved_model;
+pub use crate::saved_model::*;
+
 pub mod expr;
 
 pub mod io;
 
+pub mod layers;
+
+pub mod lookup;
+
+pub mod losses;
+
+pub mod metrics;
+
 pub mod ops;
 
 pub mod sugar;
@@ -393,6 +411,16 @@ c_enum!("Type of a single tensor element.", TF_DataType, DataType {
   value UInt64 = 23,
 });
 
+impl DataType {
+    pub(crate) fn from_proto(proto: ProtoDataType) -> DataType {
+        DataType::from_int(proto.value() as c_uint)
+    }
+
+    pub(crate) fn to_proto(&self) -> ProtoDataType {
+        ProtoDataType::from_i32(self.to_int() as i32).unwrap_or(ProtoDataType::DT_INVALID)
+    }
+}
+
 ////////////////////////
 
 /// Holds error information when communicating with back and forth with `tensorflow`.
@@ -484,6 +512,24 @@ impl From<Utf8Error> for Status {
     }
 }
 
+impl From<ProtobufError> for Status {
+    fn from(e: ProtobufError) -> Self {
+        invalid_arg!("Error encoding or decoding protocol buffer: {}", e)
+    }
+}
+
+impl From<std::io::Error> for Status {
+    fn from(e: std::io::Error) -> Self {
+        let code = match e.kind() {
+            std::io::ErrorKind::NotFound => Code::NotFound,
+            std::io::ErrorKind::PermissionDenied => Code::PermissionDenied,
+            std::io::ErrorKind::AlreadyExists => Code::AlreadyExists,
+            _ => Code::Unknown,
+        };
+        Status::new_set(code, &e.to_string().replace('\0', "")).unwrap()
+    }
+}
+
 impl From<IntoStringError> for Status {
     fn from(e: IntoStringError) -> Self {
         invalid_arg!(
@@ -822,6 +868,122 @@ impl TensorType for String {
 
 ////////////////////////
 
+/// A handle to a resource, such as a resource variable, owned by a device.
+///
+/// Tensors of type `DataType::Resource` are scalars whose contents are a
+/// serialized `ResourceHandleProto`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceHandle {
+    device: String,
+    container: String,
+    name: String,
+    hash_code: u64,
+    maybe_type_name: String,
+}
+
+impl ResourceHandle {
+    /// Returns the name of the device that owns the resource.
+    pub fn device(&self) -> &str {
+        &self.device
+    }
+
+    /// Returns the container the resource lives in.
+    pub fn container(&self) -> &str {
+        &self.container
+    }
+
+    /// Returns the name of the resource within its container.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the hash code of the resource's type.
+    pub fn hash_code(&self) -> u64 {
+        self.hash_code
+    }
+
+    /// Returns the name of the resource's type, if it was recorded.
+    pub fn maybe_type_name(&self) -> &str {
+        &self.maybe_type_name
+    }
+
+    fn to_proto(&self) -> ResourceHandleProto {
+        let mut proto = ResourceHandleProto::new();
+        proto.set_device(self.device.clone());
+        proto.set_container(self.container.clone());
+        proto.set_name(self.name.clone());
+        proto.set_hash_code(self.hash_code);
+        proto.set_maybe_type_name(self.maybe_type_name.clone());
+        proto
+    }
+
+    fn from_proto(proto: &ResourceHandleProto) -> Self {
+        ResourceHandle {
+            device: proto.get_device().to_string(),
+            container: proto.get_container().to_string(),
+            name: proto.get_name().to_string(),
+            hash_code: proto.get_hash_code(),
+            maybe_type_name: proto.get_maybe_type_name().to_string(),
+        }
+    }
+}
+
+impl Display for ResourceHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}/{}@{}", self.container, self.name, self.device)
+    }
+}
+
+impl TensorType for ResourceHandle {
+    type InnerType = TensorDataNoCRepr<ResourceHandle>;
+
+    fn data_type() -> DataType {
+        DataType::Resource
+    }
+
+    fn zero() -> Self {
+        ResourceHandle::default()
+    }
+
+    fn one() -> Self {
+        ResourceHandle::default()
+    }
+
+    fn is_repr_c() -> bool {
+        false
+    }
+
+    fn unpack(data: &[u8], count: usize) -> Result<Vec<Self>> {
+        if count != 1 {
+            return Err(invalid_arg!("Resource tensors must be scalars, but found {} elements", count));
+        }
+        let mut proto = ResourceHandleProto::new();
+        proto.merge_from_bytes(data)?;
+        Ok(vec![ResourceHandle::from_proto(&proto)])
+    }
+
+    fn packed_size(data: &[Self]) -> usize {
+        data.iter().map(|x| x.to_proto().compute_size() as usize).sum()
+    }
+
+    fn pack(data: &[Self], buffer: &mut [u8]) -> Result<()> {
+        if data.len() != 1 {
+            return Err(invalid_arg!("Resource tensors must be scalars, but found {} elements",
+                                    data.len()));
+        }
+        let bytes = data[0].to_proto().write_to_bytes()?;
+        if buffer.len() < bytes.len() {
+            return Err(invalid_arg!("Buffer of {} bytes is too small for a {} byte resource handle",
+                                    buffer.len(),
+                                    bytes.len()));
+        }
+        buffer[..bytes.len()].copy_from_slice(&bytes);
+        Ok(())
+    }
+}
+
+////////////////////////
+
 pub trait AnyTensor: Debug {
     fn inner(&self) -> Result<*mut tf::TF_Tensor>;
 }
@@ -1402,6 +1564,31 @@ impl Shape {
             Shape(Some(ref v)) => Some(v.len()),
         }
     }
+
+    pub(crate) fn from_proto(proto: &TensorShapeProto) -> Shape {
+        if proto.get_unknown_rank() {
+            return Shape(None);
+        }
+        Shape(Some(proto.get_dim()
+            .iter()
+            .map(|dim| if dim.get_size() < 0 { None } else { Some(dim.get_size()) })
+            .collect()))
+    }
+
+    pub(crate) fn to_proto(&self) -> TensorShapeProto {
+        let mut proto = TensorShapeProto::new();
+        match self.0 {
+            None => proto.set_unknown_rank(true),
+            Some(ref dims) => {
+                for dim in dims {
+                    let mut dim_proto = TensorShapeProto_Dim::new();
+                    dim_proto.set_size(dim.unwrap_or(-1));
+                    proto.mut_dim().push(dim_proto);
+                }
+            }
+        }
+        proto
+    }
 }
 
 impl From<Option<Vec<Option<i64>>>> for Shape {
@@ -1448,6 +1635,21 @@ pub use crate::while_loop::*;
 
 ////////////////////////
 
+mod gradients;
+pub use crate::gradients::*;
+
+////////////////////////
+
+mod control_flow;
+pub use crate::control_flow::*;
+
+////////////////////////
+
+mod function;
+pub use crate::function::*;
+
+////////////////////////
+
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,269 @@
+//! Lookup tables, which map keys to values in the graph, e.g. the words of a vocabulary to their
+//! indices.
+//!
+//! A table is filled by an initializer op, which is added to the `TABLE_INITIALIZERS` collection
+//! when the table is added to the graph, so `tables_initializer` can run every table's
+//! initializer at once, e.g.
+//!
+//! ```ignore
+//! let vocab = lookup::index_table_from_file("vocab.txt", -1);
+//! let ids = vocab.lookup(words);
+//! // Run lookup::tables_initializer(&mut graph)? once before fetching ids.
+//! ```
+
+use super::{Edge, GraphEdge, Operation, ResourceHandle, Result, Tensor, TensorType, new_id};
+use super::graph::{Graph, GraphOperation, TABLE_INITIALIZERS};
+use super::ops::{self, NoOp};
+use super::sugar::constant;
+use std::fmt;
+use std::rc::Rc;
+
+/// Which part of each line of a text file is used as a key or value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFileIndex {
+    /// The whole line
+    WholeLine,
+    /// The line number, starting from 0
+    LineNumber,
+    /// The column with the given index, once the line is split at the delimiter
+    Column(u32),
+}
+
+impl TextFileIndex {
+    fn attr(self) -> i64 {
+        match self {
+            TextFileIndex::WholeLine => -2,
+            TextFileIndex::LineNumber => -1,
+            TextFileIndex::Column(column) => column as i64,
+        }
+    }
+}
+
+/// Describes how a table is filled from a text file with one entry per line
+#[derive(Debug, Clone)]
+pub struct TextFileInitialiser {
+    filename: String,
+    key_index: TextFileIndex,
+    value_index: TextFileIndex,
+    vocab_size: Option<u64>,
+    delimiter: Option<String>,
+}
+
+impl TextFileInitialiser {
+    /// Takes keys and values from the given parts of each line of the file
+    pub fn new(filename: &str, key_index: TextFileIndex, value_index: TextFileIndex) -> Self {
+        Self {
+            filename: filename.to_string(),
+            key_index,
+            value_index,
+            vocab_size: None,
+            delimiter: None,
+        }
+    }
+
+    /// Only reads the first vocab_size lines. By default, every line is read.
+    pub fn vocab_size(&mut self, vocab_size: u64) -> &mut Self {
+        self.vocab_size = Some(vocab_size);
+        self
+    }
+
+    /// The string separating columns. Defaults to a tab.
+    pub fn delimiter(&mut self, delimiter: &str) -> &mut Self {
+        self.delimiter = Some(delimiter.to_string());
+        self
+    }
+}
+
+enum TableSource<K: TensorType, V: TensorType> {
+    Tensors(Edge<K>, Edge<V>),
+    TextFile(TextFileInitialiser),
+}
+
+/// A hash table which is filled once by its initializer and not modified after that
+#[derive(Clone)]
+pub struct StaticHashTable<K: TensorType, V: TensorType> {
+    table: Rc<TableOp<K, V>>,
+    handle: Edge<ResourceHandle>,
+    default_value: Edge<V>,
+}
+
+impl<K: TensorType, V: TensorType> fmt::Debug for StaticHashTable<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StaticHashTable")
+         .field("key_type", &K::data_type())
+         .field("value_type", &V::data_type())
+         .finish()
+    }
+}
+
+impl<K: TensorType, V: TensorType> StaticHashTable<K, V> {
+    /// A table mapping each of keys to the value at the same position in values. Keys which are
+    /// not in the table are mapped to default_value.
+    pub fn new(keys: Tensor<K>, values: Tensor<V>, default_value: V) -> Result<Self> {
+        if keys.dims() != values.dims() {
+            return Err(invalid_arg!("Keys have shape {:?} but values have shape {:?}",
+                                    keys.dims(), values.dims()));
+        }
+        Ok(Self::from_source(TableSource::Tensors(constant(keys), constant(values)), default_value))
+    }
+
+    /// A table filled from a text file. Keys which are not in the table are mapped to
+    /// default_value.
+    pub fn from_text_file(initialiser: TextFileInitialiser, default_value: V) -> Self {
+        Self::from_source(TableSource::TextFile(initialiser), default_value)
+    }
+
+    fn from_source(source: TableSource<K, V>, default_value: V) -> Self {
+        let table = Rc::new(TableOp {
+            table: ops::HashTableV2::build(),
+            source,
+            init_id: new_id(),
+        });
+        Self {
+            handle: Edge::new(table.clone(), 0),
+            table,
+            default_value: constant(default_value),
+        }
+    }
+
+    /// Looks up the value of each key, with the default value for keys which are not in the
+    /// table. The result has the same shape as keys.
+    pub fn lookup<E>(&self, keys: E) -> Edge<V>
+    where E: GraphEdge<K> + Clone + 'static, {
+        ops::LookupTableFindV2::new(self.handle.clone(), keys, self.default_value.clone())
+    }
+
+    /// The handle of the resource holding the table
+    pub fn handle(&self) -> Edge<ResourceHandle> {
+        self.handle.clone()
+    }
+
+    /// Returns an operation which fills the table
+    pub fn initializer(&self) -> NoOp {
+        let mut op = NoOp::build();
+        op.control_input(TableInitOp { table: self.table.clone() });
+        op
+    }
+}
+
+/// A table mapping each line of a text file to its line number, e.g. the words of a vocabulary
+/// with one word per line to their indices. Words which are not in the file are mapped to
+/// default_value.
+pub fn index_table_from_file(filename: &str, default_value: i64) -> StaticHashTable<String, i64> {
+    let initialiser = TextFileInitialiser::new(filename, TextFileIndex::WholeLine, TextFileIndex::LineNumber);
+    StaticHashTable::from_text_file(initialiser, default_value)
+}
+
+/// Returns an operation which runs the initializer of every table in the `TABLE_INITIALIZERS`
+/// collection
+///
+/// Only tables which have already been added to the graph, either directly or through an
+/// operation using them, are initialised.
+pub fn tables_initializer(graph: &mut Graph) -> NoOp {
+    let mut op = NoOp::build();
+    for init in graph.get_collection(TABLE_INITIALIZERS) {
+        op.control_input(init);
+    }
+    op
+}
+
+// Adds a table to the graph along with its initializer, and records the initializer in the
+// `TABLE_INITIALIZERS` collection.
+struct TableOp<K: TensorType, V: TensorType> {
+    table: ops::HashTableV2<K, V>,
+    source: TableSource<K, V>,
+    init_id: usize,
+}
+
+impl<K: TensorType, V: TensorType> GraphOperation for TableOp<K, V> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        let table = self.table.tf_operation(graph)?;
+        if graph.get_op_by_id(self.init_id).is_none() {
+            let handle = Edge::<ResourceHandle>::new(Rc::new(table.clone()), 0);
+            let init = match self.source {
+                TableSource::Tensors(ref keys, ref values) => {
+                    ops::InitializeTableV2::build(handle, keys.clone(), values.clone()).tf_operation(graph)?
+                }
+                TableSource::TextFile(ref file) => {
+                    let mut init = ops::InitializeTableFromTextFileV2::build(handle,
+                                                                             constant::<String, _>(file.filename.clone()),
+                                                                             file.key_index.attr(),
+                                                                             file.value_index.attr());
+                    if let Some(vocab_size) = file.vocab_size {
+                        init.vocab_size(vocab_size as i64);
+                    }
+                    if let Some(ref delimiter) = file.delimiter {
+                        init.delimiter(delimiter);
+                    }
+                    init.tf_operation(graph)?
+                }
+            };
+            graph.record_op(self.init_id, init.clone());
+            graph.add_to_collection(TABLE_INITIALIZERS, init);
+        }
+        Ok(table)
+    }
+}
+
+struct TableInitOp<K: TensorType, V: TensorType> {
+    table: Rc<TableOp<K, V>>,
+}
+
+impl<K: TensorType, V: TensorType> Clone for TableInitOp<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+        }
+    }
+}
+
+impl<K: TensorType, V: TensorType> GraphOperation for TableInitOp<K, V> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.table.tf_operation(graph)?;
+        Ok(graph.get_op_by_id(self.table.init_id).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Session, SessionOptions, SessionRun};
+    use std::fs;
+
+    fn fetch(graph: &mut Graph, values: Edge<i64>, init: NoOp) -> Vec<i64> {
+        let sess = Session::new(&SessionOptions::new(), graph).unwrap();
+        {
+            let mut run = SessionRun::new(graph);
+            run.add_op(&init).unwrap();
+            run.run(&sess).unwrap();
+        }
+        sess.fetch(graph, &values).unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_tensor_table() {
+        let mut graph = Graph::new();
+        let keys = Tensor::from(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        let values = Tensor::from(vec![10i64, 20, 30]);
+        let table = StaticHashTable::new(keys, values, -1).unwrap();
+        let found = table.lookup(constant(vec!["c".to_string(), "d".to_string(), "a".to_string()]));
+        found.operation(&mut graph).unwrap();
+        let init = tables_initializer(&mut graph);
+        assert_eq!(fetch(&mut graph, found, init), vec![30, -1, 10]);
+
+        let keys = Tensor::from(vec![1i32, 2]);
+        assert!(StaticHashTable::new(keys, Tensor::from(vec![1i64]), 0).is_err());
+    }
+
+    #[test]
+    fn test_text_file_table() {
+        let filename = std::env::temp_dir().join(format!("tensorflow_rust_vocab_{}.txt", std::process::id()));
+        fs::write(&filename, "the\ncat\nsat\n").unwrap();
+        let mut graph = Graph::new();
+        let table = index_table_from_file(filename.to_str().unwrap(), -1);
+        let found = table.lookup(constant(vec!["sat".to_string(), "dog".to_string(), "the".to_string()]));
+        let init = table.initializer();
+        assert_eq!(fetch(&mut graph, found, init), vec![2, -1, 0]);
+        fs::remove_file(&filename).unwrap();
+    }
+}
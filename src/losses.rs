@@ -0,0 +1,163 @@
+//! Loss functions built from the generated ops.
+//!
+//! Each loss is computed for every element (or, for the softmax losses, every row), multiplied by
+//! the sample weights if there are any, and then reduced according to a `Reduction`. Weights must
+//! be broadcastable to the shape of the unreduced losses, e.g. one weight per batch entry.
+
+use super::{Edge, Result, TensorType};
+use super::ops;
+use super::sugar::{constant, scalar, Float};
+
+/// How the weighted losses are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    /// Returns the weighted loss of each element
+    None,
+    /// Returns the sum of the weighted losses
+    Sum,
+    /// Returns the sum of the weighted losses divided by the number of losses
+    Mean,
+}
+
+/// Multiplies losses by weights, if there are any, and reduces them
+pub fn compute_weighted_loss<T: Float>(losses: Edge<T>, weights: Option<Edge<T>>, reduction: Reduction) -> Edge<T> {
+    let losses = match weights {
+        Some(weights) => losses * weights,
+        None => losses,
+    };
+    match reduction {
+        Reduction::None => losses,
+        Reduction::Sum => ops::Sum::new(losses.clone(), all_axes(&losses)),
+        Reduction::Mean => ops::Mean::new(losses.clone(), all_axes(&losses)),
+    }
+}
+
+// Returns the indices of every dimension of x.
+pub(crate) fn all_axes<T: TensorType>(x: &Edge<T>) -> Edge<i32> {
+    ops::Range::new(constant(0i32), ops::Rank::new(x.clone()), constant(1i32))
+}
+
+/// (predictions - labels)^2
+pub fn mean_squared_error<T: Float>(labels: Edge<T>,
+                                    predictions: Edge<T>,
+                                    weights: Option<Edge<T>>,
+                                    reduction: Reduction) -> Edge<T> {
+    compute_weighted_loss(ops::Square::new(predictions - labels), weights, reduction)
+}
+
+/// Squared error for errors up to delta, and linear in the error beyond that:
+/// 0.5 * x^2 if |x| <= delta, and 0.5 * delta^2 + delta * (|x| - delta) otherwise, where x is
+/// predictions - labels. delta must be positive.
+pub fn huber_loss<T: Float>(labels: Edge<T>,
+                            predictions: Edge<T>,
+                            delta: f64,
+                            weights: Option<Edge<T>>,
+                            reduction: Reduction) -> Result<Edge<T>> {
+    if delta <= 0.0 {
+        return Err(invalid_arg!("Huber loss delta must be positive, got {}", delta));
+    }
+    let error = ops::Abs::new(predictions - labels);
+    let quadratic = ops::Minimum::new(error.clone(), scalar::<T>(delta));
+    let linear = error - quadratic.clone();
+    let losses = ops::Square::new(quadratic) * scalar(0.5) + linear * scalar(delta);
+    Ok(compute_weighted_loss(losses, weights, reduction))
+}
+
+/// The cross entropy between labels, a probability distribution over the classes for each batch
+/// entry, and the softmax of logits. Both have shape [batch, classes], and the unreduced loss has
+/// shape [batch].
+pub fn softmax_cross_entropy<T: Float>(labels: Edge<T>,
+                                       logits: Edge<T>,
+                                       weights: Option<Edge<T>>,
+                                       reduction: Reduction) -> Edge<T> {
+    let (losses, _) = ops::SoftmaxCrossEntropyWithLogits::new(logits, labels);
+    compute_weighted_loss(losses, weights, reduction)
+}
+
+/// The cross entropy between labels, the index of the correct class for each batch entry, and
+/// the softmax of logits. labels has shape [batch] and logits [batch, classes], and the
+/// unreduced loss has shape [batch].
+pub fn sparse_softmax_cross_entropy<T, I>(labels: Edge<I>,
+                                          logits: Edge<T>,
+                                          weights: Option<Edge<T>>,
+                                          reduction: Reduction) -> Edge<T>
+where T: Float,
+      I: ops::con_or_DT_INT32_or_DT_INT64 + TensorType, {
+    let (losses, _) = ops::SparseSoftmaxCrossEntropyWithLogits::new(logits, labels);
+    compute_weighted_loss(losses, weights, reduction)
+}
+
+/// The cross entropy between labels, each the probability of an independent event, and the
+/// sigmoid of logits
+pub fn sigmoid_cross_entropy<T: Float>(labels: Edge<T>,
+                                       logits: Edge<T>,
+                                       weights: Option<Edge<T>>,
+                                       reduction: Reduction) -> Edge<T> {
+    // max(x, 0) - x * z + log(1 + exp(-|x|)) is equal to the cross entropy, but doesn't
+    // overflow for large |x|.
+    let softplus = ops::Log1p::new(ops::Exp::new(ops::Neg::new(ops::Abs::new(logits.clone()))));
+    let losses = ops::Relu::new(logits.clone()) - logits * labels + softplus;
+    compute_weighted_loss(losses, weights, reduction)
+}
+
+/// max(0, 1 - y * logits), where y is labels, which must be 0 or 1, mapped to -1 or 1
+pub fn hinge_loss<T: Float>(labels: Edge<T>,
+                            logits: Edge<T>,
+                            weights: Option<Edge<T>>,
+                            reduction: Reduction) -> Edge<T> {
+    let signs = labels * scalar(2.0) - scalar(1.0);
+    let losses = ops::Relu::new(scalar::<T>(1.0) - signs * logits);
+    compute_weighted_loss(losses, weights, reduction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Graph, GraphEdge, Session, SessionOptions, Tensor};
+
+    fn fetch(loss: Edge<f32>) -> Vec<f32> {
+        let mut graph = Graph::new();
+        loss.operation(&mut graph).unwrap();
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        sess.fetch(&mut graph, &loss).unwrap().to_vec()
+    }
+
+    fn assert_close(actual: Vec<f32>, expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_reductions() {
+        let labels = || constant(vec![0.0f32, 1.0, 2.0]);
+        let predictions = || constant(vec![1.0f32, 1.0, 4.0]);
+        let weights = || Some(constant(vec![1.0f32, 1.0, 0.5]));
+
+        assert_close(fetch(mean_squared_error(labels(), predictions(), None, Reduction::None)), &[1.0, 0.0, 4.0]);
+        assert_close(fetch(mean_squared_error(labels(), predictions(), weights(), Reduction::Sum)), &[3.0]);
+        assert_close(fetch(mean_squared_error(labels(), predictions(), weights(), Reduction::Mean)), &[1.0]);
+        let huber = huber_loss(labels(), predictions(), 1.0, None, Reduction::None).unwrap();
+        assert_close(fetch(huber), &[0.5, 0.0, 1.5]);
+        assert!(huber_loss(labels(), predictions(), 0.0, None, Reduction::None).is_err());
+    }
+
+    #[test]
+    fn test_classification_losses() {
+        let logits = || constant(Tensor::new(&[2, 2]).with_values(&[0.0f32, 0.0, 2.0, -2.0]).unwrap());
+        let one_hot = constant(Tensor::new(&[2, 2]).with_values(&[1.0f32, 0.0, 1.0, 0.0]).unwrap());
+        let expected = [2.0f32.ln(), (1.0 + (-4.0f32).exp()).ln()];
+
+        assert_close(fetch(softmax_cross_entropy(one_hot, logits(), None, Reduction::None)), &expected);
+        let sparse = sparse_softmax_cross_entropy(constant(vec![0i32, 0]), logits(), None, Reduction::Sum);
+        assert_close(fetch(sparse), &[expected[0] + expected[1]]);
+
+        let labels = || constant(vec![1.0f32, 0.0, 1.0]);
+        let logits = || constant(vec![0.0f32, 3.0, 0.5]);
+        let sigmoid = sigmoid_cross_entropy(labels(), logits(), None, Reduction::None);
+        assert_close(fetch(sigmoid), &[2.0f32.ln(), (1.0 + 3.0f32.exp()).ln(), (1.0 + (-0.5f32).exp()).ln()]);
+        assert_close(fetch(hinge_loss(labels(), logits(), None, Reduction::None)), &[1.0, 4.0, 0.5]);
+    }
+}
@@ -0,0 +1,283 @@
+//! Streaming metrics, accumulated in the graph over many runs.
+//!
+//! Each metric keeps its state in local variables, which `local_variables_initializer` or the
+//! metric's reset op initialise. Running the update op adds a batch to the state, and the value
+//! edge computes the metric from all of the batches since the last reset, e.g.
+//!
+//! ```ignore
+//! let accuracy = metrics::accuracy::<f32, _>(labels, predictions, None)?;
+//! // Run accuracy.reset() once, accuracy.update() for each batch, then fetch accuracy.value().
+//! ```
+
+use super::{Edge, RefEdge, Result, Tensor, TensorType};
+use super::losses::all_axes;
+use super::ops::{self, NoOp};
+use super::sugar::{constant, scalar, ConstantInitialiser, Float, Variable};
+use std::fmt;
+
+/// A value accumulated over many runs of an update op
+#[derive(Clone)]
+pub struct Metric<T: TensorType> {
+    value: Edge<T>,
+    update: NoOp,
+    reset: NoOp,
+}
+
+impl<T: TensorType> fmt::Debug for Metric<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Metric")
+         .field("data_type", &T::data_type())
+         .finish()
+    }
+}
+
+impl<T: TensorType> Metric<T> {
+    /// The value of the metric over every update since the last reset
+    pub fn value(&self) -> &Edge<T> {
+        &self.value
+    }
+
+    /// Adds the current inputs to the metric
+    pub fn update(&self) -> &NoOp {
+        &self.update
+    }
+
+    /// Sets the metric back to its initial state
+    pub fn reset(&self) -> &NoOp {
+        &self.reset
+    }
+}
+
+// The updates and initialisers of the variables a metric accumulates into.
+struct Accumulators {
+    update: NoOp,
+    reset: NoOp,
+}
+
+impl Accumulators {
+    fn new() -> Self {
+        Self {
+            update: NoOp::build(),
+            reset: NoOp::build(),
+        }
+    }
+
+    // Creates a local variable, initialised to zero, to which the update op adds increment.
+    fn add<T: Float>(&mut self, shape: &[u64], increment: Edge<T>) -> Result<RefEdge<T>> {
+        let mut variable = Variable::build(shape, ConstantInitialiser::new(T::zero()));
+        variable.local(true);
+        variable.trainable(false);
+        let (variable, init) = variable.finish()?;
+        self.update.control_input(ops::AssignAdd::new(variable.clone(), increment));
+        self.reset.control_input(init);
+        Ok(variable)
+    }
+
+    fn finish<T: TensorType>(self, value: Edge<T>) -> Metric<T> {
+        Metric {
+            value,
+            update: self.update,
+            reset: self.reset,
+        }
+    }
+}
+
+// Returns the sum of every element of x.
+fn sum<T: Float>(x: Edge<T>) -> Edge<T> {
+    ops::Sum::new(x.clone(), all_axes(&x))
+}
+
+// Returns the weights broadcast to the shape of like, or ones if there are none.
+fn broadcast_weights<T: Float>(like: Edge<T>, weights: Option<Edge<T>>) -> Edge<T> {
+    let ones = ops::OnesLike::new(like);
+    match weights {
+        Some(weights) => ones * weights,
+        None => ones,
+    }
+}
+
+/// The weighted mean of values
+pub fn mean<T: Float>(values: Edge<T>, weights: Option<Edge<T>>) -> Result<Metric<T>> {
+    let weights = broadcast_weights(values.clone(), weights);
+    let mut accumulators = Accumulators::new();
+    let total = accumulators.add(&[], sum(values * weights.clone()))?;
+    let count = accumulators.add(&[], sum(weights))?;
+    Ok(accumulators.finish(ops::DivNoNan::new(total, count)))
+}
+
+/// The weighted fraction of predictions which equal labels
+pub fn accuracy<T, L>(labels: Edge<L>, predictions: Edge<L>, weights: Option<Edge<T>>) -> Result<Metric<T>>
+where T: Float,
+      L: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BOOL_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF + TensorType, {
+    mean(ops::Cast::<bool, Edge<bool>, T>::new(ops::Equal::new(labels, predictions)), weights)
+}
+
+// Returns the weighted number of elements where condition is true.
+fn count<T: Float>(condition: Edge<bool>, weights: Edge<T>) -> Edge<T> {
+    sum(ops::Cast::<bool, Edge<bool>, T>::new(condition) * weights)
+}
+
+/// The weighted fraction of positive predictions which are correct, i.e. true positives divided
+/// by all positives
+pub fn precision<T: Float>(labels: Edge<bool>, predictions: Edge<bool>, weights: Option<Edge<T>>) -> Result<Metric<T>> {
+    let weights = broadcast_weights(ops::Cast::<bool, Edge<bool>, T>::new(predictions.clone()), weights);
+    let mut accumulators = Accumulators::new();
+    let true_positives = count(ops::LogicalAnd::new(labels.clone(), predictions.clone()), weights.clone());
+    let false_positives = count(ops::LogicalAnd::new(ops::LogicalNot::new(labels), predictions), weights);
+    let true_positives = accumulators.add(&[], true_positives)?;
+    let false_positives = accumulators.add(&[], false_positives)?;
+    let positives = ops::Add::new(true_positives.clone(), false_positives);
+    Ok(accumulators.finish(ops::DivNoNan::new(true_positives, positives)))
+}
+
+/// The weighted fraction of positive labels which are predicted, i.e. true positives divided by
+/// true positives plus false negatives
+pub fn recall<T: Float>(labels: Edge<bool>, predictions: Edge<bool>, weights: Option<Edge<T>>) -> Result<Metric<T>> {
+    let weights = broadcast_weights(ops::Cast::<bool, Edge<bool>, T>::new(predictions.clone()), weights);
+    let mut accumulators = Accumulators::new();
+    let true_positives = count(ops::LogicalAnd::new(labels.clone(), predictions.clone()), weights.clone());
+    let false_negatives = count(ops::LogicalAnd::new(labels, ops::LogicalNot::new(predictions)), weights);
+    let true_positives = accumulators.add(&[], true_positives)?;
+    let false_negatives = accumulators.add(&[], false_negatives)?;
+    let actual = ops::Add::new(true_positives.clone(), false_negatives);
+    Ok(accumulators.finish(ops::DivNoNan::new(true_positives, actual)))
+}
+
+/// The area under the ROC curve, for predictions in [0, 1]
+///
+/// The curve is approximated by counting the predictions above num_thresholds evenly spaced
+/// thresholds, so more thresholds give a closer approximation. num_thresholds must be at least 2.
+pub fn auc<T: Float>(labels: Edge<bool>,
+                     predictions: Edge<T>,
+                     weights: Option<Edge<T>>,
+                     num_thresholds: usize) -> Result<Metric<T>> {
+    if num_thresholds < 2 {
+        return Err(invalid_arg!("AUC requires at least 2 thresholds, got {}", num_thresholds));
+    }
+    let epsilon = 1e-7;
+    // The outer thresholds lie just beyond [0, 1], so every prediction is above the first and
+    // none are above the last.
+    let thresholds = (0..num_thresholds).map(|i| match i {
+        0 => T::from(-epsilon),
+        i if i == num_thresholds - 1 => T::from(1.0 + epsilon),
+        i => T::from(i as f32 / (num_thresholds - 1) as f32),
+    });
+    let thresholds = constant(Tensor::from_values(&[num_thresholds as u64, 1], thresholds)?);
+
+    // Compare each prediction, laid out in a row, with each threshold, laid out in a column.
+    let row = constant(vec![1i32, -1]);
+    let weights = ops::Reshape::new(broadcast_weights(predictions.clone(), weights), row.clone());
+    let above = ops::Greater::new(ops::Reshape::new(predictions, row.clone()), thresholds);
+    let below = ops::LogicalNot::new(above.clone());
+    let positive = ops::Reshape::new(labels, row);
+    let negative = ops::LogicalNot::new(positive.clone());
+    let count = |a: &Edge<bool>, b: &Edge<bool>| {
+        let matches = ops::Cast::<bool, Edge<bool>, T>::new(ops::LogicalAnd::new(a.clone(), b.clone()));
+        ops::Sum::new(matches * weights.clone(), constant(1i32))
+    };
+
+    let shape = [num_thresholds as u64];
+    let mut accumulators = Accumulators::new();
+    let true_positives = accumulators.add(&shape, count(&above, &positive))?;
+    let false_positives = accumulators.add(&shape, count(&above, &negative))?;
+    let true_negatives = accumulators.add(&shape, count(&below, &negative))?;
+    let false_negatives = accumulators.add(&shape, count(&below, &positive))?;
+
+    let epsilon = scalar::<T>(epsilon as f64);
+    let true_positive_rate = ops::Add::new(true_positives.clone(), epsilon.clone())
+        / (ops::Add::new(true_positives, false_negatives) + epsilon.clone());
+    let false_positive_rate = ops::Identity::new(false_positives.clone())
+        / (ops::Add::new(false_positives, true_negatives) + epsilon);
+    // Sum the trapezoids between neighbouring points on the curve.
+    let size = constant(vec![num_thresholds as i32 - 1]);
+    let head = |x: &Edge<T>| ops::Slice::new(x.clone(), constant(vec![0i32]), size.clone());
+    let tail = |x: &Edge<T>| ops::Slice::new(x.clone(), constant(vec![1i32]), size.clone());
+    let widths = head(&false_positive_rate) - tail(&false_positive_rate);
+    let heights = (head(&true_positive_rate) + tail(&true_positive_rate)) * scalar(0.5);
+    Ok(accumulators.finish(sum(widths * heights)))
+}
+
+/// The weighted counts of each pair of label and prediction, in a [num_classes, num_classes]
+/// matrix with a row for each label and a column for each prediction
+///
+/// labels and predictions hold class indices in [0, num_classes) and have shape [batch], as do
+/// the weights if there are any.
+pub fn confusion_matrix<T, I>(labels: Edge<I>,
+                              predictions: Edge<I>,
+                              num_classes: u64,
+                              weights: Option<Edge<T>>) -> Result<Metric<T>>
+where T: Float,
+      I: ops::con_or_DT_INT32_or_DT_UINT8_or_DT_INT64 + TensorType, {
+    let one_hot = |indices: Edge<I>| -> Edge<T> {
+        ops::OneHot::new(indices, constant(num_classes as i32), scalar(1.0), scalar(0.0))
+    };
+    let mut labels = one_hot(labels);
+    if let Some(weights) = weights {
+        labels = labels * ops::ExpandDims::new(weights, constant(1i32));
+    }
+    let counts = ops::MatMul::build(labels, one_hot(predictions)).transpose_a(true).finish();
+    let mut accumulators = Accumulators::new();
+    let matrix = accumulators.add(&[num_classes, num_classes], counts)?;
+    Ok(accumulators.finish(ops::Identity::new(matrix)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Graph, GraphEdge, Session, SessionOptions, SessionRun, local_variables_initializer};
+
+    fn run(graph: &mut Graph, sess: &Session, op: &NoOp) {
+        let mut run = SessionRun::new(graph);
+        run.add_op(op).unwrap();
+        run.run(sess).unwrap();
+    }
+
+    #[test]
+    fn test_mean() {
+        let mut graph = Graph::new();
+
+        let metric = mean(constant(vec![1.0f32, 2.0, 6.0]), Some(constant(vec![1.0f32, 1.0, 0.0]))).unwrap();
+        metric.value().operation(&mut graph).unwrap();
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        run(&mut graph, &sess, metric.reset());
+        assert_eq!(sess.fetch(&mut graph, metric.value()).unwrap()[0], 0.0);
+        run(&mut graph, &sess, metric.update());
+        run(&mut graph, &sess, metric.update());
+        assert_eq!(sess.fetch(&mut graph, metric.value()).unwrap()[0], 1.5);
+        assert_eq!(graph.local_variables().len(), 2);
+        assert_eq!(graph.global_variables().len(), 0);
+    }
+
+    #[test]
+    fn test_classification_metrics() {
+        let mut graph = Graph::new();
+
+        let labels = || constant(vec![true, true, false, false]);
+        let predictions = || constant(vec![true, false, true, true]);
+        let precision = precision::<f32>(labels(), predictions(), None).unwrap();
+        let recall = recall::<f32>(labels(), predictions(), None).unwrap();
+        let accuracy = accuracy::<f32, _>(labels(), predictions(), None).unwrap();
+        let scores = constant(vec![0.1f32, 0.4, 0.35, 0.8]);
+        let auc = auc(constant(vec![false, false, true, true]), scores, None, 201).unwrap();
+        let confusion = confusion_matrix::<f32, _>(constant(vec![0i32, 1, 1]), constant(vec![0i32, 0, 1]), 2, None).unwrap();
+        let metrics = [&precision, &recall, &accuracy, &auc, &confusion];
+        for metric in &metrics {
+            metric.value().operation(&mut graph).unwrap();
+        }
+        let init = local_variables_initializer(&mut graph).unwrap();
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        run(&mut graph, &sess, &init);
+        for metric in &metrics {
+            run(&mut graph, &sess, metric.update());
+        }
+        assert!((sess.fetch(&mut graph, precision.value()).unwrap()[0] - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(sess.fetch(&mut graph, recall.value()).unwrap()[0], 0.5);
+        assert_eq!(sess.fetch(&mut graph, accuracy.value()).unwrap()[0], 0.25);
+        assert!((sess.fetch(&mut graph, auc.value()).unwrap()[0] - 0.75).abs() < 1e-3);
+        assert_eq!(&sess.fetch(&mut graph, confusion.value()).unwrap()[..], &[1.0, 0.0, 1.0, 1.0]);
+    }
+}
@@ -0,0 +1,494 @@
+use super::train::is_variable;
+use super::version;
+use super::AnyTensor;
+use super::Code;
+use super::DataType;
+use super::FetchToken;
+use super::Graph;
+use super::GraphEdge;
+use super::Output;
+use super::Result;
+use super::Saver;
+use super::Session;
+use super::SessionRunArgs;
+use super::Shape;
+use super::Status;
+use super::Tensor;
+use super::TensorType;
+use crate::tf;
+use protobuf::Message;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use tensorflow_protos::graph::GraphDef;
+use tensorflow_protos::meta_graph;
+use tensorflow_protos::meta_graph::AssetFileDef;
+use tensorflow_protos::meta_graph::CollectionDef;
+use tensorflow_protos::meta_graph::CollectionDef_NodeList;
+use tensorflow_protos::meta_graph::MetaGraphDef;
+use tensorflow_protos::meta_graph::TensorInfo_oneof_encoding;
+use tensorflow_protos::saved_model::SavedModel;
+
+/// The key of the signature used by TensorFlow Serving when no key is given.
+pub const DEFAULT_SERVING_SIGNATURE_DEF_KEY: &str = "serving_default";
+
+/// Method name of signatures for the Classify API of TensorFlow Serving.
+pub const CLASSIFY_METHOD_NAME: &str = "tensorflow/serving/classify";
+
+/// Method name of signatures for the Predict API of TensorFlow Serving.
+pub const PREDICT_METHOD_NAME: &str = "tensorflow/serving/predict";
+
+/// Method name of signatures for the Regress API of TensorFlow Serving.
+pub const REGRESS_METHOD_NAME: &str = "tensorflow/serving/regress";
+
+/// Tag for meta graphs used for serving.
+pub const SERVE_TAG: &str = "serve";
+
+/// Tag for meta graphs used for training.
+pub const TRAIN_TAG: &str = "train";
+
+/// Collection of the string tensors which are fed with the paths of asset files.
+const ASSET_FILEPATHS_KEY: &str = "asset_filepaths";
+
+/// Describes a tensor in a `SignatureDef`: its name in the graph, type and
+/// shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorInfo {
+    name: String,
+    dtype: DataType,
+    shape: Shape,
+}
+
+impl TensorInfo {
+    /// Creates a `TensorInfo` for the tensor with the given name, e.g. `"x:0"`.
+    pub fn new<S: Into<String>>(name: S, dtype: DataType, shape: Shape) -> Self {
+        TensorInfo {
+            name: name.into(),
+            dtype,
+            shape,
+        }
+    }
+
+    /// Creates a `TensorInfo` for an edge of a graph, taking its shape from
+    /// the graph.
+    pub fn for_edge<T: TensorType, E: GraphEdge<T>>(graph: &mut Graph, edge: &E) -> Result<Self> {
+        let output = edge.output(graph)?;
+        let shape = graph.tensor_shape(output.clone())?;
+        Ok(TensorInfo::new(format!("{}:{}", output.operation.name()?, output.index),
+                           T::data_type(),
+                           shape))
+    }
+
+    /// Returns the name of the tensor in the graph, e.g. `"x:0"`.
+    ///
+    /// This is empty for sparse tensors, which are not supported.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the tensor's elements.
+    pub fn dtype(&self) -> DataType {
+        self.dtype
+    }
+
+    /// Returns the static shape of the tensor.
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    pub(crate) fn from_proto(proto: &meta_graph::TensorInfo) -> Self {
+        let name = match proto.encoding {
+            Some(TensorInfo_oneof_encoding::name(ref name)) => name.clone(),
+            _ => String::new(),
+        };
+        TensorInfo {
+            name,
+            dtype: DataType::from_proto(proto.get_dtype()),
+            shape: Shape::from_proto(proto.get_tensor_shape()),
+        }
+    }
+
+    pub(crate) fn to_proto(&self) -> meta_graph::TensorInfo {
+        let mut proto = meta_graph::TensorInfo::new();
+        proto.set_name(self.name.clone());
+        proto.set_dtype(self.dtype.to_proto());
+        proto.set_tensor_shape(self.shape.to_proto());
+        proto
+    }
+
+    /// Returns the output of the graph which this tensor refers to.
+    pub(crate) fn output(&self, graph: &Graph) -> Result<Output> {
+        if self.name.is_empty() {
+            return Err(Status::new_set(Code::Unimplemented,
+                                       "Sparse tensors in signatures are not supported").unwrap());
+        }
+        let (op_name, index) = match self.name.rfind(':') {
+            Some(pos) => {
+                let index = self.name[pos + 1..]
+                    .parse()
+                    .map_err(|_| invalid_arg!("Invalid tensor name: {}", self.name))?;
+                (&self.name[..pos], index)
+            }
+            None => (self.name.as_str(), 0),
+        };
+        Ok(Output {
+            operation: graph.operation_by_name_required(op_name)?,
+            index,
+        })
+    }
+}
+
+/// Describes a computation exported in a SavedModel, along with its named
+/// inputs and outputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureDef {
+    method_name: String,
+    inputs: HashMap<String, TensorInfo>,
+    outputs: HashMap<String, TensorInfo>,
+}
+
+impl SignatureDef {
+    /// Creates a signature without inputs or outputs.
+    pub fn new<S: Into<String>>(method_name: S) -> Self {
+        SignatureDef {
+            method_name: method_name.into(),
+            inputs: HashMap::new(),
+            outputs: HashMap::new(),
+        }
+    }
+
+    /// Adds an input to the signature, replacing any input with the same key.
+    pub fn add_input<S: Into<String>>(&mut self, key: S, info: TensorInfo) {
+        self.inputs.insert(key.into(), info);
+    }
+
+    /// Adds an output to the signature, replacing any output with the same key.
+    pub fn add_output<S: Into<String>>(&mut self, key: S, info: TensorInfo) {
+        self.outputs.insert(key.into(), info);
+    }
+
+    /// Returns the method name, e.g. `PREDICT_METHOD_NAME`.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// Returns the inputs, by key.
+    pub fn inputs(&self) -> &HashMap<String, TensorInfo> {
+        &self.inputs
+    }
+
+    /// Returns the outputs, by key.
+    pub fn outputs(&self) -> &HashMap<String, TensorInfo> {
+        &self.outputs
+    }
+
+    /// Returns the input with the given key.
+    pub fn get_input(&self, key: &str) -> Result<&TensorInfo> {
+        self.inputs.get(key).ok_or_else(|| {
+            invalid_arg!("Signature has no input {}, expected one of {:?}",
+                         key,
+                         self.inputs.keys().collect::<Vec<_>>())
+        })
+    }
+
+    /// Returns the output with the given key.
+    pub fn get_output(&self, key: &str) -> Result<&TensorInfo> {
+        self.outputs.get(key).ok_or_else(|| {
+            invalid_arg!("Signature has no output {}, expected one of {:?}",
+                         key,
+                         self.outputs.keys().collect::<Vec<_>>())
+        })
+    }
+
+    pub(crate) fn from_proto(proto: &meta_graph::SignatureDef) -> Self {
+        SignatureDef {
+            method_name: proto.get_method_name().to_string(),
+            inputs: proto.get_inputs()
+                .iter()
+                .map(|(key, info)| (key.clone(), TensorInfo::from_proto(info)))
+                .collect(),
+            outputs: proto.get_outputs()
+                .iter()
+                .map(|(key, info)| (key.clone(), TensorInfo::from_proto(info)))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn to_proto(&self) -> meta_graph::SignatureDef {
+        let mut proto = meta_graph::SignatureDef::new();
+        proto.set_method_name(self.method_name.clone());
+        for (key, info) in &self.inputs {
+            proto.mut_inputs().insert(key.clone(), info.to_proto());
+        }
+        for (key, info) in &self.outputs {
+            proto.mut_outputs().insert(key.clone(), info.to_proto());
+        }
+        proto
+    }
+}
+
+/// A signature of a loaded SavedModel, bound to the session it runs in.
+///
+/// Returned by `SavedModelBundle::signature`.
+#[derive(Debug)]
+pub struct Signature<'a> {
+    pub(crate) session: &'a Session,
+    pub(crate) def: &'a SignatureDef,
+    pub(crate) tensors: &'a HashMap<String, Output>,
+}
+
+impl<'a> Signature<'a> {
+    /// Returns the definition of the signature.
+    pub fn def(&self) -> &'a SignatureDef {
+        self.def
+    }
+
+    /// Returns the graph output to feed for the input with the given key.
+    pub fn input(&self, key: &str) -> Result<&'a Output> {
+        self.resolve(self.def.get_input(key)?)
+    }
+
+    /// Returns the graph output to fetch for the output with the given key.
+    pub fn output(&self, key: &str) -> Result<&'a Output> {
+        self.resolve(self.def.get_output(key)?)
+    }
+
+    fn resolve(&self, info: &TensorInfo) -> Result<&'a Output> {
+        self.tensors.get(info.name()).ok_or_else(|| {
+            Status::new_set(Code::Unimplemented,
+                            &format!("Tensor {:?} of the signature can't be used", info.name()))
+                .unwrap()
+        })
+    }
+
+    /// Feeds the given inputs, by key, and fetches all outputs of the
+    /// signature. The inputs may have different types, e.g.
+    /// `signature.run(&[("ids", &ids), ("features", &features)])`.
+    ///
+    /// Returns an error if a key isn't an input of the signature, or if the
+    /// type of a tensor doesn't match the type of its input.
+    pub fn run(&self, inputs: &[(&str, &dyn AnyTensor)]) -> Result<SignatureOutputs> {
+        let mut feeds = Vec::new();
+        for &(key, tensor) in inputs {
+            let info = self.def.get_input(key)?;
+            let dtype = DataType::from_c(unsafe { tf::TF_TensorType(tensor.inner()?) });
+            if info.dtype() != dtype {
+                return Err(invalid_arg!("Input {} of the signature has type {}, but got a tensor of type {}",
+                                        key,
+                                        info.dtype(),
+                                        dtype));
+            }
+            feeds.push((self.input(key)?, tensor));
+        }
+        let mut args = SessionRunArgs::new();
+        let mut tokens = HashMap::new();
+        for key in self.def.outputs().keys() {
+            tokens.insert(key.clone(), args.request_out(self.output(key)?));
+        }
+        self.session.run_with_feeds(&mut args, &feeds)?;
+        Ok(SignatureOutputs { args, tokens })
+    }
+}
+
+/// The outputs of a `Signature::run`, by key.
+#[derive(Debug)]
+pub struct SignatureOutputs {
+    args: SessionRunArgs,
+    tokens: HashMap<String, FetchToken>,
+}
+
+impl SignatureOutputs {
+    /// Takes the output with the given key. Each output can only be taken once.
+    ///
+    /// Returns an error if `T` doesn't match the type of the output.
+    pub fn get<T: TensorType>(&mut self, key: &str) -> Result<Tensor<T>> {
+        let token = *self.tokens.get(key).ok_or_else(|| {
+            invalid_arg!("Signature has no output {}, expected one of {:?}",
+                         key,
+                         self.tokens.keys().collect::<Vec<_>>())
+        })?;
+        self.args.fetch(token)
+    }
+}
+
+/// Exports a graph and the current values of its variables as a SavedModel,
+/// which can be loaded by `SavedModelBundle::load`, TensorFlow Serving or
+/// Python's `tf.saved_model.load`.
+///
+/// ```rust,ignore
+/// let mut signature = SignatureDef::new(PREDICT_METHOD_NAME);
+/// signature.add_input("x", TensorInfo::for_edge(&mut graph, &x)?);
+/// signature.add_output("y", TensorInfo::for_edge(&mut graph, &y)?);
+/// SavedModelBuilder::new()
+///     .add_tag(SERVE_TAG)
+///     .add_signature(DEFAULT_SERVING_SIGNATURE_DEF_KEY, signature)
+///     .save(&session, &mut graph, "/tmp/model")?;
+/// ```
+#[derive(Debug, Default)]
+pub struct SavedModelBuilder {
+    tags: Vec<String>,
+    signatures: HashMap<String, SignatureDef>,
+    assets: Vec<(String, PathBuf)>,
+}
+
+impl SavedModelBuilder {
+    /// Creates a builder without tags, signatures or assets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tag, which loaders use to select the meta graph.
+    pub fn add_tag<S: Into<String>>(&mut self, tag: S) -> &mut Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds a signature, replacing any signature with the same key.
+    pub fn add_signature<S: Into<String>>(&mut self, key: S, signature: SignatureDef) -> &mut Self {
+        self.signatures.insert(key.into(), signature);
+        self
+    }
+
+    /// Adds a file which is copied into the `assets` directory of the
+    /// SavedModel, e.g. a vocabulary.
+    ///
+    /// `tensor_name` names a string tensor of the graph, such as a constant
+    /// holding the original path, which loaders feed with the path of the copy.
+    pub fn add_asset<S: Into<String>, P: AsRef<Path>>(&mut self, tensor_name: S, path: P) -> &mut Self {
+        self.assets.push((tensor_name.into(), path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Writes the SavedModel to `export_dir`, which must not exist yet.
+    ///
+    /// All variables in `graph` are saved with their current values in
+    /// `session`. Operations for restoring them are added to `graph`.
+    pub fn save<P: AsRef<Path>>(&self, session: &Session, graph: &mut Graph, export_dir: P) -> Result<()> {
+        let export_dir = export_dir.as_ref();
+        if export_dir.exists() {
+            return Err(Status::new_set(Code::AlreadyExists,
+                                       &format!("Export directory {} already exists",
+                                                export_dir.display()))
+                .unwrap());
+        }
+        let variables_dir = export_dir.join("variables");
+        fs::create_dir_all(&variables_dir)?;
+
+        let mut meta_graph = MetaGraphDef::new();
+        if graph.operation_iter().any(|op| is_variable(&op)) {
+            let saver = Saver::for_all_variables(graph)?;
+            saver.write(session, &variables_dir.join("variables"))?;
+            meta_graph.set_saver_def(saver.saver_def()?);
+        }
+
+        let mut graph_def = GraphDef::new();
+        graph_def.merge_from_bytes(&graph.graph_def()?)?;
+        meta_graph.set_graph_def(graph_def);
+        {
+            let meta_info = meta_graph.mut_meta_info_def();
+            meta_info.set_tags(self.tags.clone().into());
+            meta_info.set_tensorflow_version(version()?);
+        }
+        for (key, signature) in &self.signatures {
+            meta_graph.mut_signature_def().insert(key.clone(), signature.to_proto());
+        }
+
+        if !self.assets.is_empty() {
+            let assets_dir = export_dir.join("assets");
+            fs::create_dir_all(&assets_dir)?;
+            let mut node_list = CollectionDef_NodeList::new();
+            for (tensor_name, path) in &self.assets {
+                let filename = path.file_name()
+                    .and_then(|name| name.to_str())
+                    .ok_or_else(|| invalid_arg!("Invalid asset path: {}", path.display()))?;
+                fs::copy(path, assets_dir.join(filename))?;
+                let mut asset = AssetFileDef::new();
+                asset.set_tensor_info(TensorInfo::new(tensor_name.clone(),
+                                                      DataType::String,
+                                                      Shape(Some(vec![])))
+                    .to_proto());
+                asset.set_filename(filename.to_string());
+                meta_graph.mut_asset_file_def().push(asset);
+                node_list.mut_value().push(tensor_name.clone());
+            }
+            let mut collection = CollectionDef::new();
+            collection.set_node_list(node_list);
+            meta_graph.mut_collection_def().insert(ASSET_FILEPATHS_KEY.to_string(), collection);
+        }
+
+        let mut saved_model = SavedModel::new();
+        saved_model.set_saved_model_schema_version(1);
+        saved_model.mut_meta_graphs().push(meta_graph);
+        fs::write(export_dir.join("saved_model.pb"), saved_model.write_to_bytes()?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ops;
+    use super::super::{ConstantInitialiser, SavedModelBundle, SessionOptions, SessionRun, Variable};
+
+    #[test]
+    fn save_and_load() {
+        let export_dir = std::env::temp_dir()
+            .join(format!("tensorflow_rust_saved_model_{}", std::process::id()));
+        let asset = std::env::temp_dir()
+            .join(format!("tensorflow_rust_saved_model_{}_vocab.txt", std::process::id()));
+        fs::write(&asset, "a\nb\n").unwrap();
+
+        let mut graph = Graph::new();
+        let x = ops::Placeholder::<f32>::new();
+        let (w, init) = Variable::<f32>::new(&[], ConstantInitialiser::new(1.0)).unwrap();
+        let assign = ops::Assign::build(w.clone(), ops::Const::new(3.0f32));
+        let y = ops::Mul::new(x.clone(), w.clone());
+        let n = ops::Placeholder::<i32>::new();
+        let doubled = ops::Add::new(n.clone(), n.clone());
+        let vocab_path = ops::Const::<String>::new(asset.to_str().unwrap().to_string());
+
+        let mut signature = SignatureDef::new(PREDICT_METHOD_NAME);
+        signature.add_input("x", TensorInfo::for_edge(&mut graph, &x).unwrap());
+        signature.add_output("y", TensorInfo::for_edge(&mut graph, &y).unwrap());
+        signature.add_input("n", TensorInfo::for_edge(&mut graph, &n).unwrap());
+        signature.add_output("doubled", TensorInfo::for_edge(&mut graph, &doubled).unwrap());
+        let vocab_name = TensorInfo::for_edge(&mut graph, &vocab_path).unwrap().name().to_string();
+
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        {
+            let mut run = SessionRun::new(&mut graph);
+            run.add_op(&init).unwrap();
+            run.add_op(&assign).unwrap();
+            run.run(&session).unwrap();
+        }
+        SavedModelBuilder::new()
+            .add_tag(SERVE_TAG)
+            .add_signature(DEFAULT_SERVING_SIGNATURE_DEF_KEY, signature.clone())
+            .add_asset(vocab_name, &asset)
+            .save(&session, &mut graph, &export_dir)
+            .unwrap();
+        assert!(export_dir.join("assets").join(asset.file_name().unwrap()).exists());
+        assert!(SavedModelBuilder::new().save(&session, &mut graph, &export_dir).is_err());
+
+        let mut loaded_graph = Graph::new();
+        let bundle = SavedModelBundle::load(&SessionOptions::new(),
+                                            &[SERVE_TAG],
+                                            &mut loaded_graph,
+                                            &export_dir)
+            .unwrap();
+        assert_eq!(bundle.signatures().get(DEFAULT_SERVING_SIGNATURE_DEF_KEY), Some(&signature));
+        let mut x = Tensor::<f32>::new(&[1]);
+        x[0] = 2.0;
+        let mut n = Tensor::<i32>::new(&[1]);
+        n[0] = 5;
+        let signature = bundle.signature(DEFAULT_SERVING_SIGNATURE_DEF_KEY).unwrap();
+        let mut outputs = signature.run(&[("x", &x), ("n", &n)]).unwrap();
+        assert_eq!(outputs.get::<f32>("y").unwrap()[0], 6.0);
+        assert_eq!(outputs.get::<i32>("doubled").unwrap()[0], 10);
+        assert!(signature.run(&[("x", &x), ("n", &x)]).is_err());
+
+        fs::remove_dir_all(&export_dir).unwrap();
+        fs::remove_file(&asset).unwrap();
+    }
+}
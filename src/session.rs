@@ -13,8 +13,12 @@ use super::Output;
 use super::Edge;
 use super::GraphEdge;
 use super::GraphOperation;
+use super::Signature;
+use super::SignatureDef;
 use crate::tf;
 use libc::{c_char, c_int};
+use protobuf::Message;
+use std::collections::HashMap;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::marker;
@@ -22,6 +26,7 @@ use std::marker::PhantomData;
 use std::path::Path;
 use std::ptr;
 use std::ops;
+use tensorflow_protos::meta_graph::MetaGraphDef;
 
 
 /// Aggregation type for a saved model bundle.
@@ -31,6 +36,9 @@ pub struct SavedModelBundle {
     pub session: Session,
     /// A meta graph definition as raw protocol buffer.
     pub meta_graph_def: Vec<u8>,
+    signatures: HashMap<String, SignatureDef>,
+    // Graph outputs of the tensors used by the signatures, by tensor name.
+    tensors: HashMap<String, Output>,
 }
 
 impl SavedModelBundle {
@@ -73,13 +81,51 @@ impl SavedModelBundle {
             Err(status)
         } else {
             let session = Session { inner: inner };
+            let mut meta_graph = MetaGraphDef::new();
+            meta_graph.merge_from_bytes(meta.as_ref())?;
+            let signatures: HashMap<String, SignatureDef> = meta_graph.get_signature_def()
+                .iter()
+                .map(|(key, def)| (key.clone(), SignatureDef::from_proto(def)))
+                .collect();
+            let mut tensors = HashMap::new();
+            for def in signatures.values() {
+                for info in def.inputs().values().chain(def.outputs().values()) {
+                    if !info.name().is_empty() && !tensors.contains_key(info.name()) {
+                        tensors.insert(info.name().to_string(), info.output(graph)?);
+                    }
+                }
+            }
             Ok(SavedModelBundle {
                 session: session,
-                meta_graph_def: Vec::from(meta.as_ref())
+                meta_graph_def: Vec::from(meta.as_ref()),
+                signatures,
+                tensors,
             })
         }
     }
-    
+
+    /// Returns the signatures of the loaded meta graph, by key.
+    pub fn signatures(&self) -> &HashMap<String, SignatureDef> {
+        &self.signatures
+    }
+
+    /// Returns the signature with the given key, e.g.
+    /// `DEFAULT_SERVING_SIGNATURE_DEF_KEY`, ready to be run in the bundle's
+    /// session.
+    pub fn signature(&self, key: &str) -> Result<Signature<'_>> {
+        let def = self.signatures.get(key).ok_or_else(|| {
+            Status::new_set(Code::NotFound,
+                            &format!("SavedModel has no signature {}, expected one of {:?}",
+                                     key,
+                                     self.signatures.keys().collect::<Vec<_>>()))
+                .unwrap()
+        })?;
+        Ok(Signature {
+            session: &self.session,
+            def,
+            tensors: &self.tensors,
+        })
+    }
 }
 
 /// Manages a single graph and execution.
@@ -154,16 +200,30 @@ impl Session {
     /// this may mutate variables in the graph, and the caller is responsible
     /// for handling race conditions.
     pub fn run(&self, step: &mut SessionRunArgs) -> Result<()> {
+        self.run_with_feeds(step, &[])
+    }
+
+    /// Runs the step like `run`, additionally feeding borrowed tensors, which
+    /// only need to live until the run has finished.
+    pub(crate) fn run_with_feeds(&self,
+                                 step: &mut SessionRunArgs,
+                                 feeds: &[(&Output, &dyn AnyTensor)])
+                                 -> Result<()> {
         // In case we're running it a second time and not all outputs were taken out.
         step.drop_output_tensors();
 
         let mut status = Status::new();
         let maybe_tensors: Result<_> = step.input_tensors.iter().map(|t| t.inner()).collect();
-        let input_tensors: Vec<_> = maybe_tensors?;
+        let mut input_tensors: Vec<_> = maybe_tensors?;
+        let mut input_ports = step.input_ports.clone();
+        for &(output, tensor) in feeds {
+            input_ports.push(output.to_c());
+            input_tensors.push(tensor.inner()?);
+        }
         unsafe {
             tf::TF_SessionRun(self.inner,
                               ptr::null(),
-                              step.input_ports.as_ptr(),
+                              input_ports.as_ptr(),
                               input_tensors.as_ptr() as *const *const tf::TF_Tensor,
                               input_tensors.len() as c_int,
                               step.output_ports.as_ptr(),
@@ -527,6 +587,7 @@ mod tests {
     use super::super::SessionOptions;
     use super::super::Shape;
     use super::super::Tensor;
+    use super::super::{DEFAULT_SERVING_SIGNATURE_DEF_KEY, REGRESS_METHOD_NAME};
 
     fn create_session() -> (Session, Operation, Operation) {
         let mut g = Graph::new();
@@ -603,6 +664,7 @@ mod tests {
         let SavedModelBundle {
             session,
             meta_graph_def,
+            ..
         } = bundle;
 
         assert!(!meta_graph_def.is_empty());
@@ -620,6 +682,37 @@ mod tests {
         assert_eq!(output_tensor.len(), 1);
     }
 
+    #[test]
+    fn test_savedmodelbundle_signature() {
+        let mut graph = Graph::new();
+        let bundle = SavedModelBundle::load(
+            &SessionOptions::new(),
+            &["train", "serve"],
+            &mut graph,
+            "test_resources/regression-model",
+        ).unwrap();
+
+        let signature = bundle.signature(REGRESS_METHOD_NAME).unwrap();
+        assert_eq!(signature.def().method_name(), REGRESS_METHOD_NAME);
+        let x_info = signature.def().get_input("x").unwrap();
+        assert_eq!(x_info.name(), "x:0");
+        assert_eq!(x_info.dtype(), DataType::Float);
+        assert_eq!(x_info.shape(), &Shape::from(None));
+        assert_eq!(signature.output("out").unwrap().operation.name().unwrap(), "y_hat");
+        assert!(bundle.signature(DEFAULT_SERVING_SIGNATURE_DEF_KEY).is_err());
+
+        let mut x = <Tensor<f32>>::new(&[1]);
+        x[0] = 2.0;
+        let mut y = <Tensor<f32>>::new(&[1]);
+        y[0] = 4.0;
+        let mut outputs = signature.run(&[("x", &x), ("y", &y)]).unwrap();
+        assert_eq!(outputs.get::<f32>("out").unwrap().len(), 1);
+        assert!(outputs.get::<f32>("out").is_err());
+
+        assert!(signature.run(&[("z", &x)]).is_err());
+        assert!(signature.run(&[("x", &<Tensor<i32>>::new(&[1]))]).is_err());
+    }
+
     #[test]
     fn test_device_list() {
         let (session, _, _) = create_session();
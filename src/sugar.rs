@@ -1,10 +1,45 @@
-use super::{TensorType, Tensor, Result, Operation, Shape, GraphEdge, new_id};
-use super::graph::{Edge, RefEdge, GraphOperation, Graph, Output};
+use super::{TensorType, Tensor, Result, Operation, Shape, GraphEdge, ResourceHandle, Session, SessionOptions, new_id};
+use super::graph::{Edge, RefEdge, GraphOperation, Graph, Output, GLOBAL_VARIABLES, LOCAL_VARIABLES, TRAINABLE_VARIABLES};
+use super::gradients;
 use super::ops;
 use std::ops::*;
 use std::iter::repeat;
 use std::rc::Rc;
-use std::cell::Cell;
+use std::fmt;
+
+/// The floating point types, i.e. `f32` and `f64`, which satisfy the type constraints of the ops
+/// commonly used to build neural networks, e.g. by the `layers`, `losses` and `metrics` modules.
+///
+/// This trait is sealed, so it can't be implemented for any other types.
+pub trait Float: private::Sealed
+                 + TensorType
+                 + From<f32>
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_COMPLEX64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_COMPLEX64_or_DT_COMPLEX128
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BOOL_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_INT64_or_DT_BFLOAT16_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_HALF_or_DT_UINT32_or_DT_UINT64
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_INT64_or_DT_QINT8_or_DT_BFLOAT16_or_DT_UINT16_or_DT_HALF
+                 + ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_INT64_or_DT_QINT8_or_DT_BFLOAT16_or_DT_UINT16_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 {}
+
+impl Float for f32 {}
+
+impl Float for f64 {}
+
+mod private {
+    /// Prevents `Float` being implemented outside this module
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+
+    impl Sealed for f64 {}
+}
 
 /// Add a constant node to the graph
 /// e.g.
@@ -140,6 +175,9 @@ pub struct Variable<T: TensorType> {
     initialiser: Box<dyn Initialiser<T>>,
     shape: Vec<u64>,
     validate_shape: bool,
+    trainable: bool,
+    local: bool,
+    collections: Vec<String>,
 }
 
 impl<T:TensorType> Variable<T> {
@@ -155,6 +193,9 @@ impl<T:TensorType> Variable<T> {
             initialiser: Box::new(initialiser),
             shape: shape.to_vec(),
             validate_shape: true,
+            trainable: true,
+            local: false,
+            collections: Vec::new(),
         }
     }
 
@@ -163,15 +204,104 @@ impl<T:TensorType> Variable<T> {
         self
     }
 
+    /// Whether the variable is added to the `TRAINABLE_VARIABLES` collection. Defaults to true.
+    pub fn trainable(&mut self, trainable: bool) -> &mut Self {
+        self.trainable = trainable;
+        self
+    }
+
+    /// Whether the variable is added to the `LOCAL_VARIABLES` collection instead of
+    /// `GLOBAL_VARIABLES`. Defaults to false.
+    pub fn local(&mut self, local: bool) -> &mut Self {
+        self.local = local;
+        self
+    }
+
+    /// Adds the variable to another collection
+    pub fn collection(&mut self, name: &str) -> &mut Self {
+        self.collections.push(name.to_string());
+        self
+    }
+
     /// Returns the value and initialiser op for the variable, in that order
     pub fn finish(self) -> Result<(RefEdge<T>, RefEdge<T>)> {
-        let var = self.variable.finish();
-        let init_val = constant(self.initialiser.tensor(&self.shape)?);
-        let assign = ops::Assign::build(var.clone(), init_val)
-                                 .validate_shape(self.validate_shape)
-                                 .finish();
-        Ok((var, assign))
-    } 
+        let mut collections = vec![if self.local { LOCAL_VARIABLES } else { GLOBAL_VARIABLES }.to_string()];
+        if self.trainable {
+            collections.push(TRAINABLE_VARIABLES.to_string());
+        }
+        collections.extend(self.collections);
+        let variable = Rc::new(VariableOp {
+            variable: self.variable,
+            initial_value: self.initialiser.initial_value(&self.shape)?,
+            validate_shape: self.validate_shape,
+            collections,
+            init_id: new_id(),
+        });
+        let init = RefEdge::new(Rc::new(VariableInitOp { variable: variable.clone() }), 0);
+        Ok((RefEdge::new(variable, 0), init))
+    }
+}
+
+// Adds a variable to the graph along with its initialiser, and records both in the graph's
+// collections.
+struct VariableOp<T: TensorType> {
+    variable: ops::VariableV2<T>,
+    initial_value: Edge<T>,
+    validate_shape: bool,
+    collections: Vec<String>,
+    init_id: usize,
+}
+
+impl<T: TensorType> GraphOperation for VariableOp<T> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        let variable = self.variable.tf_operation(graph)?;
+        if graph.get_op_by_id(self.init_id).is_none() {
+            let init = ops::Assign::build(RefEdge::<T>::new(Rc::new(variable.clone()), 0), self.initial_value.clone())
+                                   .validate_shape(self.validate_shape)
+                                   .tf_operation(graph)?;
+            graph.record_op(self.init_id, init.clone());
+            graph.set_variable_initializer(&variable, init)?;
+            for name in &self.collections {
+                graph.add_to_collection(name, variable.clone());
+            }
+        }
+        Ok(variable)
+    }
+}
+
+struct VariableInitOp<T: TensorType> {
+    variable: Rc<VariableOp<T>>,
+}
+
+impl<T: TensorType> GraphOperation for VariableInitOp<T> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.variable.tf_operation(graph)?;
+        Ok(graph.get_op_by_id(self.variable.init_id).unwrap())
+    }
+}
+
+/// Returns an operation which initialises every variable in the `GLOBAL_VARIABLES` collection
+///
+/// Only variables which have already been added to the graph, either directly or through an
+/// operation using them, are initialised.
+pub fn global_variables_initializer(graph: &mut Graph) -> Result<ops::NoOp> {
+    variables_initializer(graph, &graph.global_variables())
+}
+
+/// Returns an operation which initialises every variable in the `LOCAL_VARIABLES` collection
+pub fn local_variables_initializer(graph: &mut Graph) -> Result<ops::NoOp> {
+    variables_initializer(graph, &graph.local_variables())
+}
+
+fn variables_initializer(graph: &Graph, variables: &[Operation]) -> Result<ops::NoOp> {
+    let mut op = ops::NoOp::build();
+    for variable in variables {
+        match graph.variable_initializer(variable)? {
+            Some(init) => op.control_input(init),
+            None => return Err(invalid_arg!("Variable {} has no initialiser", variable.name()?)),
+        };
+    }
+    Ok(op)
 }
 
 impl<T: TensorType> Deref for Variable<T> {
@@ -188,8 +318,174 @@ impl<T: TensorType> DerefMut for Variable<T> {
 }
 
 
+/// A variable backed by a resource handle, as used by SavedModels exported from TF2.
+///
+/// The variable can be used as an edge holding its value, so it can appear in expressions
+/// and be passed to `Gradients` like any other edge. All such uses share a single read of
+/// the variable; use `read` for a fresh read, e.g. one ordered after an assignment.
+#[derive(Clone)]
+pub struct ResourceVariable<T: TensorType> {
+    handle: Edge<ResourceHandle>,
+    value: Edge<T>,
+    initializer: ops::AssignVariableOp<Edge<ResourceHandle>, T, Edge<T>>,
+}
+
+impl<T: TensorType> fmt::Debug for ResourceVariable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ResourceVariable")
+         .field("data_type", &T::data_type())
+         .finish()
+    }
+}
+
+impl<T: TensorType> ResourceVariable<T> {
+    /// Returns a variable with the given shape, set to the initialiser's value by `initializer`
+    pub fn new<I: Initialiser<T> + 'static>(shape: &[u64], initialiser: I) -> Result<Self> {
+        Self::build(shape, initialiser).finish()
+    }
+
+    /// Returns a builder for a variable with the given shape, e.g. to set attributes of its
+    /// `VarHandleOp` before calling `finish`
+    pub fn build<I: Initialiser<T> + 'static>(shape: &[u64], initialiser: I) -> ResourceVariableBuilder<T> {
+        let shape_enum = Shape(Some(shape.into_iter().map(|x| Some(*x as i64)).collect()));
+        ResourceVariableBuilder {
+            handle: ops::VarHandleOp::build(&shape_enum),
+            initialiser: Box::new(initialiser),
+            shape: shape.to_vec(),
+        }
+    }
+
+    /// The handle of the resource holding the variable
+    pub fn handle(&self) -> Edge<ResourceHandle> {
+        self.handle.clone()
+    }
+
+    /// The value of the variable, shared by every use of the variable as an edge
+    pub fn value(&self) -> Edge<T> {
+        self.value.clone()
+    }
+
+    /// Reads the value of the variable with a new `ReadVariableOp`
+    pub fn read(&self) -> Edge<T> {
+        ops::ReadVariableOp::new(self.handle.clone())
+    }
+
+    /// Returns the operation which sets the variable to its initial value
+    pub fn initializer(&self) -> ops::AssignVariableOp<Edge<ResourceHandle>, T, Edge<T>> {
+        self.initializer.clone()
+    }
+
+    /// Returns whether the variable has been initialised
+    pub fn is_initialized(&self) -> Edge<bool> {
+        ops::VarIsInitializedOp::new(self.handle.clone())
+    }
+
+    /// Returns an operation which sets the variable to `value`
+    pub fn assign<V: GraphEdge<T> + Clone + 'static>(&self, value: V) -> ops::AssignVariableOp<Edge<ResourceHandle>, T, V> {
+        ops::AssignVariableOp::build(self.handle.clone(), value)
+    }
+
+    /// Returns an operation which adds `value` to the variable
+    pub fn assign_add<V: GraphEdge<T> + Clone + 'static>(&self, value: V) -> ops::AssignAddVariableOp<Edge<ResourceHandle>, T, V> {
+        ops::AssignAddVariableOp::build(self.handle.clone(), value)
+    }
+
+    /// Returns an operation which subtracts `value` from the variable
+    pub fn assign_sub<V: GraphEdge<T> + Clone + 'static>(&self, value: V) -> ops::AssignSubVariableOp<Edge<ResourceHandle>, T, V> {
+        ops::AssignSubVariableOp::build(self.handle.clone(), value)
+    }
+}
+
+impl<T: TensorType> GraphEdge<T> for ResourceVariable<T> {
+    fn output(&self, graph: &mut Graph) -> Result<Output> {
+        self.value.output(graph)
+    }
+
+    fn operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.value.operation(graph)
+    }
+
+    fn box_clone(&self) -> Box<dyn GraphEdge<T>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<T: TensorType> GraphOperation for ResourceVariable<T> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.value.tf_operation(graph)
+    }
+}
+
+/// Builds a `ResourceVariable`, see `ResourceVariable::build`
+pub struct ResourceVariableBuilder<T: TensorType> {
+    handle: ops::VarHandleOp<T>,
+    initialiser: Box<dyn Initialiser<T>>,
+    shape: Vec<u64>,
+}
+
+impl<T: TensorType> fmt::Debug for ResourceVariableBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ResourceVariableBuilder")
+         .field("data_type", &T::data_type())
+         .field("shape", &self.shape)
+         .finish()
+    }
+}
+
+impl<T: TensorType> ResourceVariableBuilder<T> {
+    /// Returns the variable, whose initial value is created by the initialiser
+    pub fn finish(self) -> Result<ResourceVariable<T>> {
+        let handle = self.handle.finish();
+        let init_val = self.initialiser.initial_value(&self.shape)?;
+        Ok(ResourceVariable {
+            value: ops::ReadVariableOp::new(handle.clone()),
+            initializer: ops::AssignVariableOp::build(handle.clone(), init_val),
+            handle,
+        })
+    }
+}
+
+impl<T: TensorType> Deref for ResourceVariableBuilder<T> {
+    type Target = ops::VarHandleOp<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<T: TensorType> DerefMut for ResourceVariableBuilder<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.handle
+    }
+}
+
 pub trait Initialiser<T: TensorType> {
+    /// Returns the initial value of a variable with the given shape
     fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>>;
+
+    /// Returns an edge computing the initial value of a variable with the given shape. By
+    /// default this is a constant holding `tensor`, but random initialisers draw their values
+    /// in the graph.
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        Ok(constant(self.tensor(shape)?))
+    }
+}
+
+impl<T: TensorType> Initialiser<T> for Box<dyn Initialiser<T>> {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        (**self).tensor(shape)
+    }
+
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        (**self).initial_value(shape)
+    }
+}
+
+// Computes a value drawn in the graph, for initialisers asked for a tensor.
+fn evaluate<T: TensorType>(value: Edge<T>) -> Result<Tensor<T>> {
+    let mut graph = Graph::new();
+    value.output(&mut graph)?;
+    let session = Session::new(&SessionOptions::new(), &graph)?;
+    session.fetch(&mut graph, &value)
 }
 
 #[derive(Debug)]
@@ -211,14 +507,312 @@ impl<T: TensorType> Initialiser<T> for ConstantInitialiser<T> {
     }
 }
 
+/// Initialises a variable with a copy of an existing tensor, which must have the same shape
+#[derive(Debug)]
+pub struct TensorInitialiser<T: TensorType> {
+    tensor: Tensor<T>,
+}
+
+impl<T: TensorType> TensorInitialiser<T> {
+    pub fn new(tensor: Tensor<T>) -> Self {
+        Self {
+            tensor,
+        }
+    }
+}
+
+impl<T: TensorType> Initialiser<T> for TensorInitialiser<T> {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        if self.tensor.dims() != shape {
+            return Err(invalid_arg!("Expected a tensor of shape {:?}, got {:?}", shape, self.tensor.dims()));
+        }
+        Tensor::from_values(shape, self.tensor.iter().cloned())
+    }
+}
+
+/// The distribution random initialisers draw from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    /// Uniform over [0, 1)
+    Uniform,
+    /// Normal with mean 0 and standard deviation 1
+    Normal,
+    /// Normal with mean 0 and standard deviation 1, redrawing values more than 2 away from
+    /// the mean
+    TruncatedNormal,
+}
+
+// Returns random values of the given shape. If there is a seed, stateless ops are used so the
+// values only depend on the seed.
+fn random<T>(distribution: Distribution, shape: &[u64], seed: Option<i64>) -> Edge<T>
+where T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType {
+    let shape = constant(shape.iter().map(|d| *d as i64).collect::<Vec<_>>());
+    match (distribution, seed) {
+        (Distribution::Uniform, None) => ops::RandomUniform::<i64, Edge<i64>, T>::new(shape),
+        (Distribution::Normal, None) => ops::RandomStandardNormal::<i64, Edge<i64>, T>::new(shape),
+        (Distribution::TruncatedNormal, None) => ops::TruncatedNormal::<i64, Edge<i64>, T>::new(shape),
+        (Distribution::Uniform, Some(seed)) => {
+            ops::StatelessRandomUniform::<i64, Edge<i64>, i64, Edge<i64>, T>::new(shape, constant(vec![seed, 0]))
+        }
+        (Distribution::Normal, Some(seed)) => {
+            ops::StatelessRandomNormal::<i64, Edge<i64>, i64, Edge<i64>, T>::new(shape, constant(vec![seed, 0]))
+        }
+        (Distribution::TruncatedNormal, Some(seed)) => {
+            ops::StatelessTruncatedNormal::<i64, Edge<i64>, i64, Edge<i64>, T>::new(shape, constant(vec![seed, 0]))
+        }
+    }
+}
+
+// Returns value as a constant of type T.
+pub(crate) fn scalar<T: TensorType>(value: f64) -> Edge<T> {
+    ops::Cast::<f64, Edge<f64>, T>::new(constant(value))
+}
+
+/// Initialises a variable with values drawn uniformly from [min, max)
+#[derive(Debug)]
+pub struct RandomUniformInitialiser {
+    min: f64,
+    max: f64,
+    seed: Option<i64>,
+}
+
+impl RandomUniformInitialiser {
+    pub fn new(min: f64, max: f64, seed: Option<i64>) -> Self {
+        Self {
+            min,
+            max,
+            seed,
+        }
+    }
+}
+
+impl<T> Initialiser<T> for RandomUniformInitialiser
+where T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        evaluate(self.initial_value(shape)?)
+    }
+
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        let values = random(Distribution::Uniform, shape, self.seed);
+        Ok(values * scalar(self.max - self.min) + scalar(self.min))
+    }
+}
+
+/// Initialises a variable with values drawn from a normal distribution
+#[derive(Debug)]
+pub struct RandomNormalInitialiser {
+    mean: f64,
+    stddev: f64,
+    seed: Option<i64>,
+}
+
+impl RandomNormalInitialiser {
+    pub fn new(mean: f64, stddev: f64, seed: Option<i64>) -> Self {
+        Self {
+            mean,
+            stddev,
+            seed,
+        }
+    }
+}
+
+impl<T> Initialiser<T> for RandomNormalInitialiser
+where T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        evaluate(self.initial_value(shape)?)
+    }
+
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        let values = random(Distribution::Normal, shape, self.seed);
+        Ok(values * scalar(self.stddev) + scalar(self.mean))
+    }
+}
+
+/// Initialises a variable with values drawn from a normal distribution, redrawing values more
+/// than two standard deviations away from the mean
+#[derive(Debug)]
+pub struct TruncatedNormalInitialiser {
+    mean: f64,
+    stddev: f64,
+    seed: Option<i64>,
+}
+
+impl TruncatedNormalInitialiser {
+    pub fn new(mean: f64, stddev: f64, seed: Option<i64>) -> Self {
+        Self {
+            mean,
+            stddev,
+            seed,
+        }
+    }
+}
+
+impl<T> Initialiser<T> for TruncatedNormalInitialiser
+where T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        evaluate(self.initial_value(shape)?)
+    }
+
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        let values = random(Distribution::TruncatedNormal, shape, self.seed);
+        Ok(values * scalar(self.stddev) + scalar(self.mean))
+    }
+}
+
+/// Which dimensions of a variable `VarianceScalingInitialiser` scales by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FanMode {
+    /// The number of inputs to each unit
+    FanIn,
+    /// The number of outputs from each unit
+    FanOut,
+    /// The average of the number of inputs and outputs
+    FanAvg,
+}
+
+/// Initialises a variable with random values whose variance is scale divided by the number of
+/// inputs and/or outputs of each unit, as used by the Glorot and He initialisers
+///
+/// The last dimension of the variable is treated as the outputs, and the second to last as the
+/// inputs. Any other dimensions, e.g. those of a convolution kernel, multiply both.
+#[derive(Debug)]
+pub struct VarianceScalingInitialiser {
+    scale: f64,
+    mode: FanMode,
+    distribution: Distribution,
+    seed: Option<i64>,
+}
+
+impl VarianceScalingInitialiser {
+    pub fn new(scale: f64, mode: FanMode, distribution: Distribution, seed: Option<i64>) -> Self {
+        Self {
+            scale,
+            mode,
+            distribution,
+            seed,
+        }
+    }
+
+    /// The Glorot (or Xavier) uniform initialiser
+    pub fn glorot_uniform(seed: Option<i64>) -> Self {
+        Self::new(1.0, FanMode::FanAvg, Distribution::Uniform, seed)
+    }
+
+    /// The Glorot (or Xavier) normal initialiser, using a truncated normal distribution
+    pub fn glorot_normal(seed: Option<i64>) -> Self {
+        Self::new(1.0, FanMode::FanAvg, Distribution::TruncatedNormal, seed)
+    }
+
+    /// The He uniform initialiser
+    pub fn he_uniform(seed: Option<i64>) -> Self {
+        Self::new(2.0, FanMode::FanIn, Distribution::Uniform, seed)
+    }
+
+    /// The He normal initialiser, using a truncated normal distribution
+    pub fn he_normal(seed: Option<i64>) -> Self {
+        Self::new(2.0, FanMode::FanIn, Distribution::TruncatedNormal, seed)
+    }
+}
+
+impl<T> Initialiser<T> for VarianceScalingInitialiser
+where T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        evaluate(self.initial_value(shape)?)
+    }
+
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        let (fan_in, fan_out) = match shape.len() {
+            0 => (1, 1),
+            1 => (shape[0], shape[0]),
+            n => {
+                let receptive_field: u64 = shape[..n - 2].iter().product();
+                (shape[n - 2] * receptive_field, shape[n - 1] * receptive_field)
+            }
+        };
+        let n = match self.mode {
+            FanMode::FanIn => fan_in as f64,
+            FanMode::FanOut => fan_out as f64,
+            FanMode::FanAvg => (fan_in + fan_out) as f64 / 2.0,
+        };
+        let variance = self.scale / n.max(1.0);
+        let values = random(self.distribution, shape, self.seed);
+        Ok(match self.distribution {
+            // Uniform over [-limit, limit) has a variance of limit^2 / 3.
+            Distribution::Uniform => {
+                let limit = (3.0 * variance).sqrt();
+                values * scalar(2.0 * limit) - scalar(limit)
+            }
+            Distribution::Normal => values * scalar(variance.sqrt()),
+            // Truncating at two standard deviations reduces the standard deviation of a unit
+            // normal to 0.87962566103423978.
+            Distribution::TruncatedNormal => values * scalar(variance.sqrt() / 0.879_625_661_034_239_78),
+        })
+    }
+}
+
+/// Initialises a variable with a random orthogonal matrix, multiplied by gain
+///
+/// Variables with more than two dimensions are treated as a matrix with one column for each
+/// element of the last dimension. The variable must have at least two dimensions.
+#[derive(Debug)]
+pub struct OrthogonalInitialiser {
+    gain: f64,
+    seed: Option<i64>,
+}
+
+impl OrthogonalInitialiser {
+    pub fn new(gain: f64, seed: Option<i64>) -> Self {
+        Self {
+            gain,
+            seed,
+        }
+    }
+}
+
+impl<T> Initialiser<T> for OrthogonalInitialiser
+where T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_COMPLEX64_or_DT_COMPLEX128,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: ops::con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF {
+    fn tensor(&self, shape: &[u64]) -> Result<Tensor<T>> {
+        evaluate(self.initial_value(shape)?)
+    }
+
+    fn initial_value(&self, shape: &[u64]) -> Result<Edge<T>> {
+        if shape.len() < 2 {
+            return Err(invalid_arg!("Orthogonal initialiser requires at least 2 dimensions, got shape {:?}", shape));
+        }
+        let cols = shape[shape.len() - 1];
+        let rows = shape[..shape.len() - 1].iter().product::<u64>();
+        let values = random::<T>(Distribution::Normal, &[rows.max(cols), rows.min(cols)], self.seed);
+        let (q, r) = ops::Qr::new(values);
+        // Make the decomposition unique, so q is uniformly distributed.
+        let mut q = q * ops::Sign::new(ops::MatrixDiagPart::new(r));
+        if rows < cols {
+            q = ops::Transpose::new(q, constant(vec![1, 0]));
+        }
+        let shape = constant(shape.iter().map(|d| *d as i64).collect::<Vec<_>>());
+        Ok(ops::Reshape::new(q, shape) * scalar(self.gain))
+    }
+}
+
 
 pub struct Gradients<T: TensorType> {   
     prefix: Option<String>,
     x: Vec<Box<dyn GraphEdge<T>>>,
     y: Vec<Box<dyn GraphEdge<T>>>,
     dx: Option<Vec<Box<GraphEdge<T>>>>,
+    // Identifies the first y in each graph the gradients have been added to.
+    id: usize,
     ids: Vec<usize>,
-    in_graph: Cell<bool>,
 }
 
 impl<T: TensorType> Gradients<T> {
@@ -236,11 +830,11 @@ impl<T: TensorType> Gradients<T> {
                 None => None,
                 Some(s) => Some(s.to_string()),
             },
+            id: new_id(),
             ids: (0..x.len()).map(|_| new_id()).collect(),
             x,
             y,
             dx: None,
-            in_graph: Cell::new(false),
         }
     }
 
@@ -282,33 +876,33 @@ impl<T: TensorType> Gradients<T> {
             }
         };
 
-        let outputs = graph.add_gradients(self.prefix.as_ref().map(|s| s.as_str()),
-                                          &ys,
-                                          &xs,
-                                          dxs.as_ref().map(|v| v.as_slice()))?;
+        let outputs = gradients::add_gradients(graph,
+                                               self.prefix.as_ref().map(|s| s.as_str()),
+                                               &ys,
+                                               &xs,
+                                               dxs.as_ref().map(|v| v.as_slice()))?;
         for (output, id) in outputs.into_iter().zip(&self.ids) {
             match output {
-                Some(o) => graph.record_op(*id, o.operation),
+                Some(o) => graph.record_output(*id, o),
                 None => {},
             }
         }
-        self.in_graph.set(true);
+        if let Some(y) = ys.into_iter().next() {
+            graph.record_op(self.id, y.operation);
+        }
         Ok(())
     }
 
     fn output(&self, graph: &mut Graph, idx: usize) -> Result<Option<Output>> {
-        Ok(match self.operation(graph, idx)? {
-            None => None,
-            Some(op) => Some(Output{operation: op, index: 0}),
-        })
-    }
-
-    fn operation(&self, graph: &mut Graph, idx: usize) -> Result<Option<Operation>> {
-        if !self.in_graph.get() {
+        if graph.get_op_by_id(self.id).is_none() {
             self.make_operations(graph)?
         };
 
-        Ok(graph.get_op_by_id(self.ids[idx]))
+        Ok(graph.get_output_by_id(self.ids[idx]))
+    }
+
+    fn operation(&self, graph: &mut Graph, idx: usize) -> Result<Option<Operation>> {
+        Ok(self.output(graph, idx)?.map(|output| output.operation))
     }
 }
 
@@ -431,6 +1025,44 @@ mod tests {
         assert_eq!(tensor, vec![0, 1, 1].into());
     }
 
+    #[test]
+    fn test_split() {
+        let mut graph = Graph::new();
+
+        let a = constant(vec![1, 2, 3, 4]);
+        let pieces = ops::Split::new(constant(0), a, 2);
+        assert_eq!(pieces.len(), 2);
+
+        let b = constant(vec![5, 6, 7]);
+        let rows = ops::Unpack::new(b, 3);
+        assert_eq!(rows.len(), 3);
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+
+        let tensor = sess.fetch(&mut graph, &pieces[1]).unwrap();
+        assert_eq!(tensor, vec![3, 4].into());
+        let tensor = sess.fetch(&mut graph, &rows[2]).unwrap();
+        assert_eq!(tensor, 7.into());
+    }
+
+    #[test]
+    fn test_outputs() {
+        let mut graph = Graph::new();
+
+        let top = ops::TopKV2::build(constant(vec![3, 1, 4, 1, 5]), constant(2)).outputs();
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+
+        let tensor = sess.fetch(&mut graph, &top.values).unwrap();
+        assert_eq!(tensor, vec![5, 4].into());
+        let tensor = sess.fetch(&mut graph, &top.indices).unwrap();
+        assert_eq!(tensor, vec![4, 2].into());
+        assert_eq!(top.values.operation(&mut graph).unwrap().name().unwrap(),
+                   top.indices.operation(&mut graph).unwrap().name().unwrap());
+    }
+
     #[test]
     fn test_constant_init() {
         let mut graph = Graph::new();
@@ -454,6 +1086,123 @@ mod tests {
         assert_eq!(result, vec![3, 3].into());
     }
 
+    #[test]
+    fn test_init_tensor() {
+        assert_eq!(ConstantInitialiser::new(2.0f32).tensor(&[2]).unwrap(), vec![2.0f32, 2.0].into());
+        let uniform = RandomUniformInitialiser::new(-1.0, 3.0, Some(1));
+        let values = Initialiser::<f32>::tensor(&uniform, &[100]).unwrap();
+        assert_eq!(values.dims(), &[100]);
+        assert!(values.iter().all(|x| *x >= -1.0 && *x < 3.0));
+    }
+
+    #[test]
+    fn test_variable_collections() {
+        let mut graph = Graph::new();
+        let (a, _) = Variable::<i32>::new(&[2], ConstantInitialiser::new(1)).unwrap();
+        let mut b = Variable::build(&[2], ConstantInitialiser::new(2));
+        b.op_name("b");
+        b.trainable(false).collection("custom");
+        let (b, _) = b.finish().unwrap();
+        let mut c = Variable::build(&[2], ConstantInitialiser::new(3));
+        c.op_name("c");
+        c.local(true);
+        let (c, _) = c.finish().unwrap();
+        let sum = ops::Add::new(ops::Add::new(a.clone(), b), c);
+        sum.operation(&mut graph).unwrap();
+
+        let names = |ops: Vec<Operation>| ops.iter().map(|op| op.name().unwrap()).collect::<Vec<_>>();
+        let a_name = a.operation(&mut graph).unwrap().name().unwrap();
+        assert_eq!(names(graph.global_variables()), vec![a_name.clone(), "b".to_string()]);
+        assert_eq!(names(graph.trainable_variables()), vec![a_name]);
+        assert_eq!(names(graph.local_variables()), vec!["c"]);
+        assert_eq!(names(graph.get_collection("custom")), vec!["b"]);
+
+        let global_init = global_variables_initializer(&mut graph).unwrap();
+        let local_init = local_variables_initializer(&mut graph).unwrap();
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        {
+            let mut run = SessionRun::new(&mut graph);
+            run.add_op(&global_init).unwrap();
+            run.add_op(&local_init).unwrap();
+            run.run(&sess).unwrap();
+        }
+        assert_eq!(sess.fetch(&mut graph, &sum).unwrap(), vec![6, 6].into());
+    }
+
+    #[test]
+    fn test_random_init() {
+        let mut graph = Graph::new();
+        let (uniform, uniform_init) = Variable::<f32>::new(&[100], RandomUniformInitialiser::new(-1.0, 3.0, Some(1))).unwrap();
+        let (seeded, seeded_init) = Variable::<f32>::new(&[100], RandomUniformInitialiser::new(-1.0, 3.0, Some(1))).unwrap();
+        let (normal, normal_init) = Variable::<f64>::new(&[100], TruncatedNormalInitialiser::new(5.0, 0.5, None)).unwrap();
+        let (glorot, glorot_init) = Variable::<f32>::new(&[10, 20], VarianceScalingInitialiser::glorot_uniform(None)).unwrap();
+        let (orthogonal, orthogonal_init) = Variable::<f64>::new(&[4, 3], OrthogonalInitialiser::new(2.0, Some(3))).unwrap();
+        let tensor = Tensor::new(&[2]).with_values(&[1.5f32, 2.5]).unwrap();
+        let (copy, copy_init) = Variable::<f32>::new(&[2], TensorInitialiser::new(tensor)).unwrap();
+        assert!(Variable::<f64>::new(&[3], OrthogonalInitialiser::new(1.0, None)).is_err());
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+        {
+            let mut run = SessionRun::new(&mut graph);
+            for init in &[uniform_init, seeded_init, glorot_init, copy_init] {
+                run.add_op(init).unwrap();
+            }
+            run.add_op(&normal_init).unwrap();
+            run.add_op(&orthogonal_init).unwrap();
+            run.run(&sess).unwrap();
+        }
+
+        let uniform = sess.fetch(&mut graph, &uniform).unwrap();
+        assert!(uniform.iter().all(|x| *x >= -1.0 && *x < 3.0));
+        assert_eq!(uniform, sess.fetch(&mut graph, &seeded).unwrap());
+        let normal = sess.fetch(&mut graph, &normal).unwrap();
+        assert!(normal.iter().all(|x| (x - 5.0).abs() <= 1.0));
+        // The limit for Glorot uniform is sqrt(6 / (fan_in + fan_out))
+        let glorot = sess.fetch(&mut graph, &glorot).unwrap();
+        assert!(glorot.iter().all(|x| x.abs() <= (6.0f32 / 30.0).sqrt()));
+        assert_eq!(sess.fetch(&mut graph, &copy).unwrap(), vec![1.5f32, 2.5].into());
+
+        // The columns are orthogonal with a norm of gain
+        let m = sess.fetch(&mut graph, &orthogonal).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot: f64 = (0..4).map(|k| m[k * 3 + i] * m[k * 3 + j]).sum();
+                let expected = if i == j { 4.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resource_variable() {
+        let mut graph = Graph::new();
+        let var = ResourceVariable::<f32>::new(&[2], ConstantInitialiser::new(3.0)).unwrap();
+        let assign = var.assign_add(constant(vec![1.0f32, 2.0]));
+        let doubled = var.value() * constant(2.0f32);
+        let dy = Gradients::new(None, vec![doubled], vec![var.clone()]).edges(&mut graph).unwrap();
+
+        let options = SessionOptions::new();
+        let sess = Session::new(&options, &graph).unwrap();
+
+        {
+            let mut run = SessionRun::new(&mut graph);
+            run.add_op(&var.initializer()).unwrap();
+            run.run(&sess).unwrap();
+        }
+        {
+            let mut run = SessionRun::new(&mut graph);
+            run.add_op(&assign).unwrap();
+            run.run(&sess).unwrap();
+        }
+
+        let tensor = sess.fetch(&mut graph, &var.read()).unwrap();
+        assert_eq!(tensor, vec![4.0f32, 5.0].into());
+        let tensor = sess.fetch(&mut graph, dy[0].as_ref().unwrap()).unwrap();
+        assert_eq!(tensor, vec![2.0f32, 2.0].into());
+    }
+
     #[test]
     fn test_gradients() {
         let mut graph = Graph::new();
@@ -1,5 +1,15 @@
-use super::{Gradients, TensorType, GraphRefEdge, GraphEdge, Graph, Result, Variable, constant, ConstantInitialiser};
+use super::{Gradients, TensorType, GraphRefEdge, GraphEdge, GraphOperation, Graph, Result, Variable, constant, ConstantInitialiser};
+use super::{AnyEdge, Edge, Operation, RefEdge, ResourceVariable, Session, SessionRunArgs, Tensor};
+use super::ops::{Identity, Placeholder, RestoreV2, SaveV2};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use tensorflow_protos::saver::{SaverDef, SaverDef_CheckpointFormatVersion};
 use super::ops::{ApplyGradientDescent, NoOp, con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64, Sub, Mul};
+use super::ops::{ApplyAdagrad, ApplyAdam, ApplyMomentum, ApplyRMSProp, Assign, AssignAdd, Cast, ClipByValue, Cos, Floor, L2Loss, LessEqual, Maximum, Minimum, Pow, Select, Sqrt, con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF, con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_COMPLEX64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF, con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_INT64_or_DT_BFLOAT16_or_DT_HALF, con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF, con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF};
+use std::cell::RefCell;
+use std::f64::consts::PI;
 
 /// Returns an operation that performs gradient descent on vars, based on cost and alpha
 /// prefix is prefixed to the names of the gradient operations
@@ -27,11 +37,868 @@ where
     Ok(op)
 }
 
+/// Returns the gradients of cost with respect to vars, paired with their variables.
+/// Variables which cost does not depend on are left out.
+/// prefix is prefixed to the names of the gradient operations
+pub fn compute_gradients<T, V, I, C>(graph: &mut Graph,
+                                     cost: C,
+                                     vars: I,
+                                     prefix: Option<&str>) -> Result<Vec<(Edge<T>, V)>>
+where T: TensorType,
+      V: GraphRefEdge<T> + Clone + 'static,
+      I: IntoIterator<Item=V>,
+      C: GraphEdge<T> + 'static, {
+    let vars: Vec<V> = vars.into_iter().collect();
+    let gradients = Gradients::new(prefix, Some(cost), vars.clone()).edges(graph)?;
+
+    let mut grads_and_vars = Vec::new();
+    for (gradient, var) in gradients.into_iter().zip(vars) {
+        if let Some(gradient) = gradient {
+            grads_and_vars.push((to_edge(graph, &gradient)?, var));
+        }
+    }
+    Ok(grads_and_vars)
+}
+
+/// Creates an int64 scalar variable named "global_step" to count training steps, starting from
+/// zero. Returns the variable and its initialiser op, in that order.
+///
+/// Pass the variable to an optimizer's `global_step` method to increment it each time the
+/// optimizer runs, or wrap another training op with `increment_global_step`.
+///
+/// Like other variables, it is only added to the graph when it is first used, so it must be
+/// added, e.g. by calling `operation`, before `global_variables_initializer` for that to
+/// initialise it.
+pub fn global_step() -> Result<(RefEdge<i64>, RefEdge<i64>)> {
+    let mut variable = Variable::build(&[], ConstantInitialiser::new(0i64));
+    variable.op_name("global_step");
+    variable.trainable(false);
+    variable.finish()
+}
+
+/// Returns an operation which runs train and then increments global_step.
+pub fn increment_global_step<O>(global_step: &RefEdge<i64>, train: O) -> NoOp
+where O: GraphOperation + Clone + 'static, {
+    let mut increment = AssignAdd::build(global_step.clone(), constant(1i64));
+    increment.control_input(train);
+    let mut op = NoOp::build();
+    op.control_input(increment);
+    op
+}
+
+// Learning rate schedules compute their decay factor in double precision and cast it to the
+// type of the learning rate.
+
+fn step_as_f64<S: GraphEdge<i64> + Clone + 'static>(global_step: S) -> Edge<f64> {
+    Cast::<i64, S, f64>::new(global_step)
+}
+
+fn scale_by<T, L>(learning_rate: L, factor: Edge<f64>) -> Edge<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF + TensorType,
+      L: GraphEdge<T> + Clone + 'static, {
+    Mul::new(learning_rate, Cast::<f64, Edge<f64>, T>::new(factor))
+}
+
+/// Returns a learning rate of learning_rate * decay_rate ^ (global_step / decay_steps).
+/// If staircase is true the exponent is rounded down, so the learning rate drops every
+/// decay_steps steps.
+pub fn exponential_decay<T, S>(learning_rate: T,
+                               global_step: S,
+                               decay_steps: i64,
+                               decay_rate: f64,
+                               staircase: bool) -> Edge<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF + TensorType,
+      S: GraphEdge<i64> + Clone + 'static, {
+    let mut exponent = step_as_f64(global_step) / constant(decay_steps as f64);
+    if staircase {
+        exponent = Floor::new(exponent);
+    }
+    scale_by(constant(learning_rate), Pow::new(constant(decay_rate), exponent))
+}
+
+/// Returns a learning rate of values[0] until global_step passes boundaries[0], then values[1]
+/// until it passes boundaries[1], and so on. There must be one more value than boundaries.
+pub fn piecewise_constant<T, S>(global_step: S, boundaries: &[i64], values: &[T]) -> Result<Edge<T>>
+where T: TensorType,
+      S: GraphEdge<i64> + Clone + 'static, {
+    if values.len() != boundaries.len() + 1 {
+        return Err(invalid_arg!("Expected {} values for {} boundaries, got {}",
+                                boundaries.len() + 1,
+                                boundaries.len(),
+                                values.len()));
+    }
+    let mut learning_rate = constant(values[boundaries.len()].clone());
+    for (boundary, value) in boundaries.iter().zip(values).rev() {
+        let before = LessEqual::new(global_step.clone(), constant(*boundary));
+        learning_rate = Select::new(before, constant(value.clone()), learning_rate);
+    }
+    Ok(learning_rate)
+}
+
+/// Returns a learning rate which follows half a cosine curve from learning_rate down to
+/// alpha * learning_rate over decay_steps steps, and stays there afterwards.
+pub fn cosine_decay<T, S>(learning_rate: T, global_step: S, decay_steps: i64, alpha: f64) -> Edge<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF + TensorType,
+      S: GraphEdge<i64> + Clone + 'static, {
+    let decay_steps = constant(decay_steps as f64);
+    let progress = Minimum::new(step_as_f64(global_step), decay_steps.clone()) / decay_steps;
+    let cosine = constant(0.5f64) * (constant(1.0f64) + Cos::new(constant(PI) * progress));
+    scale_by(constant(learning_rate), constant(1.0 - alpha) * cosine + constant(alpha))
+}
+
+/// Scales learning_rate, which may itself be a schedule, linearly from zero up to its full value
+/// over the first warmup_steps steps.
+pub fn linear_warmup<T, L, S>(learning_rate: L, global_step: S, warmup_steps: i64) -> Edge<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF + TensorType,
+      L: GraphEdge<T> + Clone + 'static,
+      S: GraphEdge<i64> + Clone + 'static, {
+    let fraction = step_as_f64(global_step) / constant(warmup_steps as f64);
+    scale_by(learning_rate, Minimum::new(fraction, constant(1.0f64)))
+}
+
+/// Returns the combined L2 norm of all of the given tensors.
+pub fn global_norm<T>(tensors: &[Edge<T>]) -> Edge<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_COMPLEX64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF, {
+    // L2Loss is half the sum of squares.
+    let half_sum = tensors.iter()
+                          .map(|t| L2Loss::new(t.clone()))
+                          .fold(constant(T::zero()), |sum, l| sum + l);
+    Sqrt::new(half_sum.clone() + half_sum)
+}
+
+/// Scales the gradients so that their global norm is at most clip_norm, returning the clipped
+/// gradients and the global norm before clipping.
+///
+/// Like the other gradient transformations, this works on the output of `compute_gradients`,
+/// and the result can be passed to any `Optimizer`'s `apply_gradients`:
+///
+/// ```ignore
+/// let grads_and_vars = compute_gradients(&mut graph, cost, vars, None)?;
+/// let (grads_and_vars, norm) = clip_by_global_norm(grads_and_vars, 5.0);
+/// let train = optimizer.apply_gradients(&mut graph, &grads_and_vars)?;
+/// ```
+pub fn clip_by_global_norm<T, V>(grads_and_vars: Vec<(Edge<T>, V)>, clip_norm: T) -> (Vec<(Edge<T>, V)>, Edge<T>)
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_BFLOAT16_or_DT_HALF + TensorType,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_COMPLEX64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_INT64_or_DT_BFLOAT16_or_DT_HALF,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF, {
+    let grads: Vec<Edge<T>> = grads_and_vars.iter().map(|(grad, _)| grad.clone()).collect();
+    let norm = global_norm(&grads);
+    let clip_norm = constant(clip_norm);
+    let scale = clip_norm.clone() / Maximum::new(norm.clone(), clip_norm);
+    let grads_and_vars = grads_and_vars.into_iter()
+                                       .map(|(grad, var)| (grad * scale.clone(), var))
+                                       .collect();
+    (grads_and_vars, norm)
+}
+
+/// Clips each element of the gradients to the range [min, max].
+pub fn clip_by_value<T, V>(grads_and_vars: Vec<(Edge<T>, V)>, min: T, max: T) -> Vec<(Edge<T>, V)>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 + TensorType, {
+    let min = constant(min);
+    let max = constant(max);
+    grads_and_vars.into_iter()
+                  .map(|(grad, var)| (ClipByValue::new(grad, min.clone(), max.clone()), var))
+                  .collect()
+}
+
+/// Adds decay times each variable to its gradient, which is equivalent to adding an L2 penalty
+/// of decay / 2 times the squared norm of the variables to the cost.
+pub fn l2_decay<T, V>(grads_and_vars: Vec<(Edge<T>, V)>, decay: T) -> Vec<(Edge<T>, V)>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF + TensorType,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_STRING_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_COMPLEX128_or_DT_HALF,
+      V: GraphRefEdge<T> + Clone + 'static, {
+    let decay = constant(decay);
+    grads_and_vars.into_iter()
+                  .map(|(grad, var)| (grad + Mul::new(decay.clone(), var.clone()), var))
+                  .collect()
+}
+
+/// Multiplies the gradients by scale, e.g. to undo the scaling of the cost when using loss
+/// scaling.
+pub fn scale_gradients<T, V>(grads_and_vars: Vec<(Edge<T>, V)>, scale: T) -> Vec<(Edge<T>, V)>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF + TensorType, {
+    let scale = constant(scale);
+    grads_and_vars.into_iter()
+                  .map(|(grad, var)| (grad * scale.clone(), var))
+                  .collect()
+}
+
+// Adds edge to the graph and returns a plain edge pointing at the same output.
+fn to_edge<T: TensorType>(graph: &mut Graph, edge: &dyn GraphEdge<T>) -> Result<Edge<T>> {
+    let output = edge.output(graph)?;
+    Ok(Edge::new(Rc::new(output.operation), output.index))
+}
+
+/// Updates variables to minimize a cost.
+///
+/// Optimizers which keep per-variable state create their slot variables when the gradients are
+/// applied. The slots must be initialised by running the optimizer's `initializer` before
+/// training.
+pub trait Optimizer<T: TensorType> {
+    /// Returns an operation which applies each gradient to its variable.
+    fn apply_gradients<V>(&self, graph: &mut Graph, grads_and_vars: &[(Edge<T>, V)]) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static;
+
+    /// Returns an operation which takes one step towards minimizing cost by updating vars.
+    fn minimize<V, I, C>(&self, graph: &mut Graph, cost: C, vars: I) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static,
+          I: IntoIterator<Item=V>,
+          C: GraphEdge<T> + 'static, {
+        let grads_and_vars = compute_gradients(graph, cost, vars, None)?;
+        self.apply_gradients(graph, &grads_and_vars)
+    }
+
+    /// Returns an operation which takes one step towards minimizing cost by updating the
+    /// variables of type T in the graph's `TRAINABLE_VARIABLES` collection.
+    fn minimize_trainable<C>(&self, graph: &mut Graph, cost: C) -> Result<NoOp>
+    where C: GraphEdge<T> + 'static, {
+        // Variables are only added to the collection when the graph first uses them.
+        cost.output(graph)?;
+        let mut vars = Vec::new();
+        for op in graph.trainable_variables() {
+            if op.get_attr_type("dtype")? == T::data_type() {
+                vars.push(RefEdge::<T>::new(Rc::new(op), 0));
+            }
+        }
+        self.minimize(graph, cost, vars)
+    }
+}
+
+// State shared by all optimizers.
+struct OptimizerBase<T: TensorType> {
+    learning_rate: Box<dyn GraphEdge<T>>,
+    global_step: Option<RefEdge<i64>>,
+    // Initialisers of the variables created by the optimizer.
+    initialisers: RefCell<Vec<RefEdge<T>>>,
+}
+
+impl<T: TensorType> OptimizerBase<T> {
+    fn new<L: GraphEdge<T> + 'static>(learning_rate: L) -> Self {
+        Self {
+            learning_rate: Box::new(learning_rate),
+            global_step: None,
+            initialisers: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn learning_rate(&self, graph: &mut Graph) -> Result<Edge<T>> {
+        to_edge(graph, &*self.learning_rate)
+    }
+
+    // Creates a variable with the same shape as var, named after it.
+    fn slot<V: GraphEdge<T>>(&self, graph: &mut Graph, var: &V, name: &str, value: T) -> Result<RefEdge<T>> {
+        let output = var.output(graph)?;
+        let var_name = output.operation.name()?;
+        let shape = match graph.tensor_shape(output)?.0 {
+            Some(ref dims) if dims.iter().all(Option::is_some) => {
+                dims.iter().map(|d| d.unwrap() as u64).collect::<Vec<_>>()
+            }
+            _ => return Err(invalid_arg!("Variable {} does not have a fully defined shape", var_name)),
+        };
+        self.variable(graph, &format!("{}/{}", var_name, name), &shape, value)
+    }
+
+    // Creates a variable which is initialised along with the slots. The name is used as is,
+    // regardless of the current name scope.
+    fn variable(&self, graph: &mut Graph, name: &str, shape: &[u64], value: T) -> Result<RefEdge<T>> {
+        let name = graph.new_op_name(&format!("{}_{{}}", name))?;
+        let mut variable = Variable::build(shape, ConstantInitialiser::new(value));
+        variable.op_name(&name);
+        variable.trainable(false);
+        let (var, init) = variable.finish()?;
+        // Add the variable straight away so the next one can't be given the same name.
+        graph.with_scope("", |graph| var.operation(graph))?;
+        self.initialisers.borrow_mut().push(init);
+        Ok(var)
+    }
+
+    fn initializer(&self) -> NoOp {
+        let mut op = NoOp::build();
+        for init in self.initialisers.borrow().iter() {
+            op.control_input(init.clone());
+        }
+        op
+    }
+
+    // Returns an operation which runs updates and then increments the global step, if any.
+    fn finish(&self, updates: NoOp) -> NoOp {
+        match self.global_step {
+            None => updates,
+            Some(ref global_step) => increment_global_step(global_step, updates),
+        }
+    }
+}
+
+macro_rules! optimizer_common_methods {
+    () => {
+        /// Sets a variable which is incremented each time the optimizer is run. The variable is
+        /// only added to the graph along with the training op, see `train::global_step`.
+        pub fn global_step(&mut self, global_step: RefEdge<i64>) -> &mut Self {
+            self.base.global_step = Some(global_step);
+            self
+        }
+
+        /// Returns an operation which initialises the variables created by the optimizer.
+        pub fn initializer(&self) -> NoOp {
+            self.base.initializer()
+        }
+    };
+}
+
+/// Optimizer which subtracts the gradient scaled by the learning rate from each variable.
+pub struct GradientDescentOptimizer<T: TensorType> {
+    base: OptimizerBase<T>,
+}
+
+impl<T: TensorType> GradientDescentOptimizer<T> {
+    /// Creates an optimizer with the given learning rate, which may be a schedule.
+    pub fn new<L: GraphEdge<T> + 'static>(learning_rate: L) -> Self {
+        Self {
+            base: OptimizerBase::new(learning_rate),
+        }
+    }
+
+    optimizer_common_methods!();
+}
+
+impl<T> Optimizer<T> for GradientDescentOptimizer<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 + TensorType, {
+    fn apply_gradients<V>(&self, graph: &mut Graph, grads_and_vars: &[(Edge<T>, V)]) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static, {
+        let learning_rate = self.base.learning_rate(graph)?;
+        let mut updates = NoOp::build();
+        for (grad, var) in grads_and_vars {
+            updates.control_input(ApplyGradientDescent::new(var.clone(), learning_rate.clone(), grad.clone()));
+        }
+        Ok(self.base.finish(updates))
+    }
+}
+
+/// Optimizer which accumulates a velocity for each variable, decayed by `momentum` on each
+/// step.
+pub struct MomentumOptimizer<T: TensorType> {
+    base: OptimizerBase<T>,
+    momentum: T,
+    use_nesterov: bool,
+}
+
+impl<T: TensorType> MomentumOptimizer<T> {
+    /// Creates an optimizer with the given learning rate, which may be a schedule.
+    pub fn new<L: GraphEdge<T> + 'static>(learning_rate: L, momentum: T) -> Self {
+        Self {
+            base: OptimizerBase::new(learning_rate),
+            momentum,
+            use_nesterov: false,
+        }
+    }
+
+    /// Whether to use Nesterov momentum. Defaults to false.
+    pub fn use_nesterov(&mut self, use_nesterov: bool) -> &mut Self {
+        self.use_nesterov = use_nesterov;
+        self
+    }
+
+    optimizer_common_methods!();
+}
+
+impl<T> Optimizer<T> for MomentumOptimizer<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 + TensorType, {
+    fn apply_gradients<V>(&self, graph: &mut Graph, grads_and_vars: &[(Edge<T>, V)]) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static, {
+        let learning_rate = self.base.learning_rate(graph)?;
+        let momentum = constant(self.momentum.clone());
+        let mut updates = NoOp::build();
+        for (grad, var) in grads_and_vars {
+            let accum = self.base.slot(graph, var, "momentum", T::zero())?;
+            updates.control_input(ApplyMomentum::build(var.clone(),
+                                                       accum,
+                                                       learning_rate.clone(),
+                                                       grad.clone(),
+                                                       momentum.clone())
+                                  .use_nesterov(self.use_nesterov)
+                                  .finish());
+        }
+        Ok(self.base.finish(updates))
+    }
+}
+
+/// Optimizer which scales the learning rate for each element by the inverse square root of its
+/// accumulated squared gradients.
+pub struct AdagradOptimizer<T: TensorType> {
+    base: OptimizerBase<T>,
+    initial_accumulator_value: T,
+}
+
+impl<T: TensorType + From<f32>> AdagradOptimizer<T> {
+    /// Creates an optimizer with the given learning rate, which may be a schedule.
+    pub fn new<L: GraphEdge<T> + 'static>(learning_rate: L) -> Self {
+        Self {
+            base: OptimizerBase::new(learning_rate),
+            initial_accumulator_value: T::from(0.1),
+        }
+    }
+
+    /// Starting value of the accumulators. Defaults to 0.1.
+    pub fn initial_accumulator_value(&mut self, initial_accumulator_value: T) -> &mut Self {
+        self.initial_accumulator_value = initial_accumulator_value;
+        self
+    }
+
+    optimizer_common_methods!();
+}
+
+impl<T> Optimizer<T> for AdagradOptimizer<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 + TensorType, {
+    fn apply_gradients<V>(&self, graph: &mut Graph, grads_and_vars: &[(Edge<T>, V)]) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static, {
+        let learning_rate = self.base.learning_rate(graph)?;
+        let mut updates = NoOp::build();
+        for (grad, var) in grads_and_vars {
+            let accum = self.base.slot(graph, var, "accumulator", self.initial_accumulator_value.clone())?;
+            updates.control_input(ApplyAdagrad::new(var.clone(), accum, learning_rate.clone(), grad.clone()));
+        }
+        Ok(self.base.finish(updates))
+    }
+}
+
+/// Optimizer which divides the gradient by a moving average of its recent magnitude.
+pub struct RMSPropOptimizer<T: TensorType> {
+    base: OptimizerBase<T>,
+    decay: T,
+    momentum: T,
+    epsilon: T,
+}
+
+impl<T: TensorType + From<f32>> RMSPropOptimizer<T> {
+    /// Creates an optimizer with the given learning rate, which may be a schedule.
+    pub fn new<L: GraphEdge<T> + 'static>(learning_rate: L) -> Self {
+        Self {
+            base: OptimizerBase::new(learning_rate),
+            decay: T::from(0.9),
+            momentum: T::from(0.0),
+            epsilon: T::from(1e-10),
+        }
+    }
+
+    /// Discount factor of the moving average. Defaults to 0.9.
+    pub fn decay(&mut self, decay: T) -> &mut Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Defaults to 0.
+    pub fn momentum(&mut self, momentum: T) -> &mut Self {
+        self.momentum = momentum;
+        self
+    }
+
+    /// Small value added to the denominator to avoid dividing by zero. Defaults to 1e-10.
+    pub fn epsilon(&mut self, epsilon: T) -> &mut Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    optimizer_common_methods!();
+}
+
+impl<T> Optimizer<T> for RMSPropOptimizer<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 + TensorType, {
+    fn apply_gradients<V>(&self, graph: &mut Graph, grads_and_vars: &[(Edge<T>, V)]) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static, {
+        let learning_rate = self.base.learning_rate(graph)?;
+        let decay = constant(self.decay.clone());
+        let momentum = constant(self.momentum.clone());
+        let epsilon = constant(self.epsilon.clone());
+        let mut updates = NoOp::build();
+        for (grad, var) in grads_and_vars {
+            let ms = self.base.slot(graph, var, "rms", T::one())?;
+            let mom = self.base.slot(graph, var, "momentum", T::zero())?;
+            updates.control_input(ApplyRMSProp::new(var.clone(),
+                                                    ms,
+                                                    mom,
+                                                    learning_rate.clone(),
+                                                    decay.clone(),
+                                                    momentum.clone(),
+                                                    epsilon.clone(),
+                                                    grad.clone()));
+        }
+        Ok(self.base.finish(updates))
+    }
+}
+
+/// Optimizer implementing Adam, which scales each step by bias-corrected estimates of the first
+/// and second moments of the gradient.
+pub struct AdamOptimizer<T: TensorType> {
+    base: OptimizerBase<T>,
+    beta1: T,
+    beta2: T,
+    epsilon: T,
+}
+
+impl<T: TensorType + From<f32>> AdamOptimizer<T> {
+    /// Creates an optimizer with the given learning rate, which may be a schedule.
+    pub fn new<L: GraphEdge<T> + 'static>(learning_rate: L) -> Self {
+        Self {
+            base: OptimizerBase::new(learning_rate),
+            beta1: T::from(0.9),
+            beta2: T::from(0.999),
+            epsilon: T::from(1e-8),
+        }
+    }
+
+    /// Decay rate of the first moment estimates. Defaults to 0.9.
+    pub fn beta1(&mut self, beta1: T) -> &mut Self {
+        self.beta1 = beta1;
+        self
+    }
+
+    /// Decay rate of the second moment estimates. Defaults to 0.999.
+    pub fn beta2(&mut self, beta2: T) -> &mut Self {
+        self.beta2 = beta2;
+        self
+    }
+
+    /// Small value added to the denominator to avoid dividing by zero. Defaults to 1e-8.
+    pub fn epsilon(&mut self, epsilon: T) -> &mut Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    optimizer_common_methods!();
+}
+
+impl<T> Optimizer<T> for AdamOptimizer<T>
+where T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_QINT8_or_DT_QUINT8_or_DT_QINT32_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF_or_DT_UINT32_or_DT_UINT64 + TensorType,
+      T: con_or_DT_FLOAT_or_DT_DOUBLE_or_DT_INT32_or_DT_UINT8_or_DT_INT16_or_DT_INT8_or_DT_COMPLEX64_or_DT_INT64_or_DT_BFLOAT16_or_DT_UINT16_or_DT_COMPLEX128_or_DT_HALF, {
+    fn apply_gradients<V>(&self, graph: &mut Graph, grads_and_vars: &[(Edge<T>, V)]) -> Result<NoOp>
+    where V: GraphRefEdge<T> + Clone + 'static, {
+        let learning_rate = self.base.learning_rate(graph)?;
+        let beta1 = constant(self.beta1.clone());
+        let beta2 = constant(self.beta2.clone());
+        let epsilon = constant(self.epsilon.clone());
+        let beta1_power = self.base.variable(graph, &graph.scoped_name("beta1_power"), &[], self.beta1.clone())?;
+        let beta2_power = self.base.variable(graph, &graph.scoped_name("beta2_power"), &[], self.beta2.clone())?;
+        let mut updates = NoOp::build();
+        for (grad, var) in grads_and_vars {
+            let m = self.base.slot(graph, var, "m", T::zero())?;
+            let v = self.base.slot(graph, var, "v", T::zero())?;
+            updates.control_input(ApplyAdam::new(var.clone(),
+                                                 m,
+                                                 v,
+                                                 beta1_power.clone(),
+                                                 beta2_power.clone(),
+                                                 learning_rate.clone(),
+                                                 beta1.clone(),
+                                                 beta2.clone(),
+                                                 epsilon.clone(),
+                                                 grad.clone()));
+        }
+
+        // The powers of beta are only updated once all variables have been updated with the
+        // old values.
+        let mut update_beta1 = Assign::build(beta1_power.clone(), Mul::new(beta1_power, beta1));
+        update_beta1.control_input(updates.clone());
+        let mut update_beta2 = Assign::build(beta2_power.clone(), Mul::new(beta2_power, beta2));
+        update_beta2.control_input(updates.clone());
+        let mut op = NoOp::build();
+        op.control_input(update_beta1);
+        op.control_input(update_beta2);
+        Ok(self.base.finish(op))
+    }
+}
+
+
+/// A variable which can be saved to and restored from checkpoints by a `Saver`.
+pub trait Saveable {
+    /// Returns the operation holding the variable, adding it to the graph if needed.
+    fn variable_op(&self, graph: &mut Graph) -> Result<Operation>;
+}
+
+impl<T: TensorType> Saveable for RefEdge<T> {
+    fn variable_op(&self, graph: &mut Graph) -> Result<Operation> {
+        self.operation(graph)
+    }
+}
+
+impl<T: TensorType> Saveable for ResourceVariable<T> {
+    fn variable_op(&self, graph: &mut Graph) -> Result<Operation> {
+        self.handle().operation(graph)
+    }
+}
+
+impl Saveable for Operation {
+    fn variable_op(&self, _graph: &mut Graph) -> Result<Operation> {
+        Ok(self.clone())
+    }
+}
+
+impl<'a, S: Saveable + ?Sized> Saveable for &'a S {
+    fn variable_op(&self, graph: &mut Graph) -> Result<Operation> {
+        (**self).variable_op(graph)
+    }
+}
+
+/// Saves and restores variables using V2 checkpoints, as written by Python's `tf.train.Saver`.
+///
+/// Each variable is stored under the name of the operation holding it, so checkpoints can be
+/// exchanged with Python as long as the variables are named the same way.
+#[derive(Debug)]
+pub struct Saver {
+    // Placeholder for the path prefix of the checkpoint.
+    prefix: Operation,
+    // Identity of the path prefix, which runs after the variables are saved.
+    save: Operation,
+    // NoOp which runs after all variables are restored.
+    restore: Operation,
+}
+
+impl Saver {
+    /// Creates a saver for the given variables, adding the save and restore operations to the
+    /// graph.
+    pub fn new<S, I>(graph: &mut Graph, vars: I) -> Result<Self>
+    where S: Saveable,
+          I: IntoIterator<Item=S>, {
+        let mut ops = Vec::new();
+        for var in vars {
+            ops.push(var.variable_op(graph)?);
+        }
+        if ops.is_empty() {
+            return Err(invalid_arg!("No variables to save"));
+        }
+
+        let mut names = Vec::new();
+        let mut dtypes = Vec::new();
+        let mut values = Vec::new();
+        let mut resource = Vec::new();
+        for op in &ops {
+            let is_resource = match op.op_type()?.as_str() {
+                "Variable" | "VariableV2" => false,
+                "VarHandleOp" => true,
+                op_type => return Err(invalid_arg!("Operation {} of type {} is not a variable",
+                                                   op.name()?,
+                                                   op_type)),
+            };
+            let dtype = op.get_attr_type("dtype")?;
+            let value = if is_resource {
+                let name = graph.new_op_name("ReadVariableOp_{}")?;
+                let mut nd = graph.new_operation("ReadVariableOp", &name)?;
+                nd.add_input(op.clone());
+                nd.set_attr_type("dtype", dtype)?;
+                nd.finish()?
+            } else {
+                op.clone()
+            };
+            names.push(op.name()?);
+            dtypes.push(dtype);
+            values.push(AnyEdge::new(Rc::new(value), 0, dtype));
+            resource.push(is_resource);
+        }
+
+        let prefix = Placeholder::<String>::new();
+        let slices = constant(vec![String::new(); names.len()]);
+        let names = constant(names);
+        let save_op = SaveV2::build(prefix.clone(), names.clone(), slices.clone(), &values)
+            .tf_operation(graph)?;
+        let mut save = Identity::build(prefix.clone());
+        save.control_input(save_op);
+        let save = save.finish().operation(graph)?;
+
+        let mut restore = NoOp::build();
+        let restored = RestoreV2::new(prefix.clone(), names, slices, &dtypes);
+        for ((op, value), is_resource) in ops.into_iter().zip(restored).zip(resource) {
+            let op_type = if is_resource { "AssignVariableOp" } else { "Assign" };
+            let name = graph.new_op_name(&format!("{}_{{}}", op_type))?;
+            let value = value.output(graph)?;
+            let mut nd = graph.new_operation(op_type, &name)?;
+            nd.add_input(op);
+            nd.add_input(value);
+            restore.control_input(nd.finish()?);
+        }
+
+        Ok(Saver {
+            prefix: prefix.operation(graph)?,
+            save,
+            restore: restore.tf_operation(graph)?,
+        })
+    }
+
+    /// Creates a saver for every variable in the graph.
+    pub fn for_all_variables(graph: &mut Graph) -> Result<Self> {
+        let vars: Vec<Operation> = graph.operation_iter().filter(is_variable).collect();
+        Saver::new(graph, vars)
+    }
+
+    /// Saves the variables to the checkpoint with the given path prefix.
+    ///
+    /// The index and data files are written next to the prefix, and the `checkpoint` state file
+    /// in the same directory is updated so that `latest_checkpoint` returns this checkpoint.
+    pub fn save<P: AsRef<Path>>(&self, session: &Session, path: P) -> Result<()> {
+        let path = path.as_ref();
+        self.write(session, path)?;
+        update_checkpoint_state(path)
+    }
+
+    /// Saves the variables without updating the `checkpoint` state file.
+    pub(crate) fn write(&self, session: &Session, path: &Path) -> Result<()> {
+        let mut step = SessionRunArgs::new();
+        step.add_feed(&self.prefix, 0, checkpoint_prefix(path)?);
+        step.add_target(&self.save);
+        session.run(&mut step)
+    }
+
+    /// Restores the variables from the checkpoint with the given path prefix.
+    pub fn restore<P: AsRef<Path>>(&self, session: &Session, path: P) -> Result<()> {
+        let mut step = SessionRunArgs::new();
+        step.add_feed(&self.prefix, 0, checkpoint_prefix(path.as_ref())?);
+        step.add_target(&self.restore);
+        session.run(&mut step)
+    }
+
+    /// Describes the saver's operations, so that loaders of a SavedModel can restore variables.
+    pub(crate) fn saver_def(&self) -> Result<SaverDef> {
+        let mut saver_def = SaverDef::new();
+        saver_def.set_filename_tensor_name(format!("{}:0", self.prefix.name()?));
+        saver_def.set_save_tensor_name(format!("{}:0", self.save.name()?));
+        saver_def.set_restore_op_name(self.restore.name()?);
+        saver_def.set_version(SaverDef_CheckpointFormatVersion::V2);
+        Ok(saver_def)
+    }
+}
+
+const VARIABLE_OP_TYPES: &[&str] = &["Variable", "VariableV2", "VarHandleOp"];
+
+/// Returns true if the operation holds a variable which a `Saver` can save.
+pub(crate) fn is_variable(op: &Operation) -> bool {
+    match op.op_type() {
+        Ok(op_type) => VARIABLE_OP_TYPES.contains(&op_type.as_str()),
+        Err(_) => false,
+    }
+}
+
+const CHECKPOINT_STATE_FILE: &str = "checkpoint";
+
+fn checkpoint_prefix(path: &Path) -> Result<Tensor<String>> {
+    let prefix = path.to_str().ok_or_else(|| invalid_arg!("Invalid checkpoint path"))?;
+    Ok(Tensor::from(prefix.to_string()))
+}
+
+fn checkpoint_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(dir) if dir != Path::new("") => dir,
+        _ => Path::new("."),
+    }
+}
+
+/// Reads the `model_checkpoint_path` and `all_model_checkpoint_paths` fields of a
+/// `CheckpointState` text proto.
+fn read_checkpoint_state(dir: &Path) -> Result<Option<(String, Vec<String>)>> {
+    let contents = match fs::read_to_string(dir.join(CHECKPOINT_STATE_FILE)) {
+        Ok(contents) => contents,
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut latest = None;
+    let mut all = Vec::new();
+    for line in contents.lines() {
+        let mut parts = line.splitn(2, ':');
+        let key = parts.next().unwrap_or("").trim();
+        let value = match parts.next() {
+            Some(value) => parse_text_string(value.trim())?,
+            None => continue,
+        };
+        match key {
+            "model_checkpoint_path" => latest = Some(value),
+            "all_model_checkpoint_paths" => all.push(value),
+            _ => {}
+        }
+    }
+    Ok(latest.map(|latest| (latest, all)))
+}
+
+fn parse_text_string(s: &str) -> Result<String> {
+    if s.len() < 2 || !s.starts_with('"') || !s.ends_with('"') {
+        return Err(invalid_arg!("Invalid string in checkpoint state: {}", s));
+    }
+    let mut out = String::new();
+    let mut chars = s[1..s.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some(c) => out.push(c),
+            None => return Err(invalid_arg!("Invalid string in checkpoint state: {}", s)),
+        }
+    }
+    Ok(out)
+}
+
+fn format_text_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn update_checkpoint_state(path: &Path) -> Result<()> {
+    let dir = checkpoint_dir(path);
+    let name = path.file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| invalid_arg!("Invalid checkpoint path"))?
+        .to_string();
+    let mut all = match read_checkpoint_state(dir)? {
+        Some((_, all)) => all,
+        None => Vec::new(),
+    };
+    all.retain(|p| *p != name);
+    all.push(name.clone());
+    let mut contents = format!("model_checkpoint_path: {}\n", format_text_string(&name));
+    for p in &all {
+        contents.push_str(&format!("all_model_checkpoint_paths: {}\n", format_text_string(p)));
+    }
+    fs::write(dir.join(CHECKPOINT_STATE_FILE), contents)?;
+    Ok(())
+}
+
+/// Returns the path prefix of the most recently saved checkpoint in `dir`, as recorded in its
+/// `checkpoint` state file.
+///
+/// Returns `None` if there is no state file, or if the checkpoint it refers to doesn't exist.
+pub fn latest_checkpoint<P: AsRef<Path>>(dir: P) -> Result<Option<PathBuf>> {
+    let dir = dir.as_ref();
+    let latest = match read_checkpoint_state(dir)? {
+        Some((latest, _)) => dir.join(latest),
+        None => return Ok(None),
+    };
+    let mut index = latest.clone().into_os_string();
+    index.push(".index");
+    if Path::new(&index).exists() {
+        Ok(Some(latest))
+    } else {
+        Ok(None)
+    }
+}
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use super::super::{Graph, SessionOptions, Session, SessionRun, Variable};
+  use super::super::{Graph, SessionOptions, Session, SessionRun, Variable, ResourceVariable, global_variables_initializer};
 
   #[test]
   fn test_gradient_descent() {
@@ -63,4 +930,261 @@ mod tests {
     let result = sess.fetch(&mut graph, &var).unwrap();
     assert_eq!(result[0], 3.6);
   }
+
+  #[test]
+  fn test_momentum_optimizer() {
+    let mut graph = Graph::new();
+
+    let (var, init) = Variable::<f64>::new(&[1], ConstantInitialiser::new(4.0)).unwrap();
+    let (step, step_init) = Variable::<i64>::new(&[], ConstantInitialiser::new(0)).unwrap();
+    let diff = Sub::new(var.clone(), constant(2.0));
+    let cost = Mul::new(diff.clone(), diff.clone());
+
+    let mut optimizer = MomentumOptimizer::new(constant(0.1), 0.5);
+    optimizer.global_step(step.clone());
+    let train = optimizer.minimize(&mut graph, cost, Some(var.clone())).unwrap();
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&init).unwrap();
+      run.add_op(&step_init).unwrap();
+      run.add_op(&optimizer.initializer()).unwrap();
+      run.run(&sess).unwrap();
+    }
+
+    // The gradient is 4, then 3.2 with an accumulated velocity of 0.5 * 4 + 3.2
+    let expected = [3.6, 3.08];
+    for (i, expected) in expected.iter().enumerate() {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&train).unwrap();
+      run.run(&sess).unwrap();
+      assert!((sess.fetch(&mut graph, &var).unwrap()[0] - expected).abs() < 1e-12);
+      assert_eq!(sess.fetch(&mut graph, &step).unwrap()[0], i as i64 + 1);
+    }
+  }
+
+  #[test]
+  fn test_minimize_trainable() {
+    let mut graph = Graph::new();
+
+    let (var, _) = Variable::<f32>::new(&[1], ConstantInitialiser::new(4.0)).unwrap();
+    let (unused, _) = Variable::<f64>::new(&[1], ConstantInitialiser::new(0.0)).unwrap();
+    let (step, _) = global_step().unwrap();
+    unused.operation(&mut graph).unwrap();
+    step.operation(&mut graph).unwrap();
+    let diff = Sub::new(var.clone(), constant(2.0f32));
+    let cost = Mul::new(diff.clone(), diff.clone());
+
+    let mut optimizer = AdamOptimizer::new(constant(0.1f32));
+    optimizer.global_step(step.clone());
+    let train = optimizer.minimize_trainable(&mut graph, cost).unwrap();
+    let init = global_variables_initializer(&mut graph).unwrap();
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&init).unwrap();
+      run.run(&sess).unwrap();
+    }
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&train).unwrap();
+      run.run(&sess).unwrap();
+    }
+    assert!((sess.fetch(&mut graph, &var).unwrap()[0] - 3.9).abs() < 1e-4);
+    assert_eq!(sess.fetch(&mut graph, &step).unwrap()[0], 1);
+  }
+
+  #[test]
+  fn test_adam_optimizer() {
+    let mut graph = Graph::new();
+
+    let (var, init) = Variable::<f32>::new(&[2], ConstantInitialiser::new(4.0)).unwrap();
+    let diff = Sub::new(var.clone(), constant(vec![2.0f32, 6.0]));
+    let cost = Mul::new(diff.clone(), diff.clone());
+
+    let optimizer = AdamOptimizer::new(constant(0.1f32));
+    let train = optimizer.minimize(&mut graph, cost, Some(var.clone())).unwrap();
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&init).unwrap();
+      run.add_op(&optimizer.initializer()).unwrap();
+      run.run(&sess).unwrap();
+    }
+
+    // Adam moves each element by roughly the learning rate on every step
+    for _ in 0..2 {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&train).unwrap();
+      run.run(&sess).unwrap();
+    }
+    let result = sess.fetch(&mut graph, &var).unwrap();
+    assert!((result[0] - 3.8).abs() < 1e-3);
+    assert!((result[1] - 4.2).abs() < 1e-3);
+  }
+
+  #[test]
+  fn test_learning_rate_schedules() {
+    let mut graph = Graph::new();
+
+    let (step, step_init) = global_step().unwrap();
+    let exponential = exponential_decay(1.0f32, step.clone(), 10, 0.5, false);
+    let staircase = exponential_decay(1.0f32, step.clone(), 10, 0.5, true);
+    let piecewise = piecewise_constant(step.clone(), &[5, 10], &[1.0f32, 0.5, 0.25]).unwrap();
+    let cosine = cosine_decay(1.0f32, step.clone(), 10, 0.2);
+    let warmup = linear_warmup(staircase.clone(), step.clone(), 10);
+    assert!(piecewise_constant(step.clone(), &[5], &[1.0f32]).is_err());
+
+    let (var, init) = Variable::<f32>::new(&[1], ConstantInitialiser::new(0.0)).unwrap();
+    let mut optimizer = GradientDescentOptimizer::new(exponential.clone());
+    optimizer.global_step(step.clone());
+    let train = optimizer.minimize(&mut graph, Mul::new(var.clone(), constant(-1.0f32)), Some(var.clone())).unwrap();
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&step_init).unwrap();
+      run.add_op(&init).unwrap();
+      run.run(&sess).unwrap();
+    }
+
+    // Each row is the expected exponential, staircase, piecewise, cosine and warmup rates
+    let expected = [
+      (0, [1.0, 1.0, 1.0, 1.0, 0.0]),
+      (5, [0.5f32.sqrt(), 1.0, 1.0, 0.6, 0.5]),
+      (10, [0.5, 0.5, 0.5, 0.2, 0.5]),
+      (15, [0.5f32.powf(1.5), 0.5, 0.25, 0.2, 0.5]),
+    ];
+    let schedules = [&exponential, &staircase, &piecewise, &cosine, &warmup];
+    for &(n, ref rates) in expected.iter() {
+      let assign = Assign::new(step.clone(), constant(n as i64));
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&assign).unwrap();
+      run.run(&sess).unwrap();
+      for (schedule, rate) in schedules.iter().zip(rates) {
+        assert!((sess.fetch(&mut graph, *schedule).unwrap()[0] - rate).abs() < 1e-6);
+      }
+    }
+
+    // The train op increments the global step after applying the decayed learning rate
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&train).unwrap();
+      run.run(&sess).unwrap();
+    }
+    assert_eq!(sess.fetch(&mut graph, &step).unwrap()[0], 16);
+    assert!((sess.fetch(&mut graph, &var).unwrap()[0] - 0.5f32.powf(1.5)).abs() < 1e-6);
+  }
+
+  #[test]
+  fn test_clip_by_global_norm() {
+    let mut graph = Graph::new();
+
+    let (a, a_init) = Variable::<f32>::new(&[1], ConstantInitialiser::new(3.0)).unwrap();
+    let (b, b_init) = Variable::<f32>::new(&[1], ConstantInitialiser::new(4.0)).unwrap();
+    // The gradients are 6 and 8, with a global norm of 10
+    let cost = Mul::new(a.clone(), a.clone()) + Mul::new(b.clone(), b.clone());
+
+    let grads_and_vars = compute_gradients(&mut graph, cost, vec![a.clone(), b.clone()], None).unwrap();
+    let (grads_and_vars, norm) = clip_by_global_norm(grads_and_vars, 5.0);
+    let train = GradientDescentOptimizer::new(constant(1.0f32))
+      .apply_gradients(&mut graph, &grads_and_vars).unwrap();
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&a_init).unwrap();
+      run.add_op(&b_init).unwrap();
+      run.run(&sess).unwrap();
+    }
+    assert!((sess.fetch(&mut graph, &norm).unwrap()[0] - 10.0).abs() < 1e-5);
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&train).unwrap();
+      run.run(&sess).unwrap();
+    }
+    assert!(sess.fetch(&mut graph, &a).unwrap()[0].abs() < 1e-5);
+    assert!(sess.fetch(&mut graph, &b).unwrap()[0].abs() < 1e-5);
+  }
+
+  #[test]
+  fn test_gradient_transformations() {
+    let mut graph = Graph::new();
+
+    let (var, init) = Variable::<f64>::new(&[2], ConstantInitialiser::new(2.0)).unwrap();
+    let cost = Mul::new(var.clone(), constant(vec![1.0, 10.0]));
+
+    let grads_and_vars = compute_gradients(&mut graph, cost, Some(var.clone()), None).unwrap();
+    let grads_and_vars = scale_gradients(grads_and_vars, 0.5);
+    let grads_and_vars = l2_decay(grads_and_vars, 0.25);
+    let grads_and_vars = clip_by_value(grads_and_vars, -3.0, 3.0);
+    let grad = grads_and_vars[0].0.clone();
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&init).unwrap();
+      run.run(&sess).unwrap();
+    }
+    assert_eq!(sess.fetch(&mut graph, &grad).unwrap(), vec![1.0, 3.0].into());
+  }
+
+  #[test]
+  fn test_saver() {
+    let dir = std::env::temp_dir().join(format!("tensorflow_rust_saver_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    assert_eq!(latest_checkpoint(&dir).unwrap(), None);
+
+    let mut graph = Graph::new();
+    let (var, init) = Variable::<f32>::new(&[2], ConstantInitialiser::new(1.0)).unwrap();
+    let resource = ResourceVariable::<i64>::new(&[], ConstantInitialiser::new(7)).unwrap();
+    let assign = Assign::build(var.clone(), constant(vec![5.0f32, 6.0]));
+    let vars: Vec<&dyn Saveable> = vec![&var, &resource];
+    let saver = Saver::new(&mut graph, vars).unwrap();
+    assert!(Saver::for_all_variables(&mut graph).is_ok());
+
+    let options = SessionOptions::new();
+    let sess = Session::new(&options, &graph).unwrap();
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&init).unwrap();
+      run.add_op(&resource.initializer()).unwrap();
+      run.run(&sess).unwrap();
+    }
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&assign).unwrap();
+      run.run(&sess).unwrap();
+    }
+    let path = dir.join("model.ckpt");
+    saver.save(&sess, &path).unwrap();
+    assert_eq!(latest_checkpoint(&dir).unwrap(), Some(path.clone()));
+
+    {
+      let mut run = SessionRun::new(&mut graph);
+      run.add_op(&init).unwrap();
+      run.run(&sess).unwrap();
+    }
+    saver.restore(&sess, &path).unwrap();
+    assert_eq!(sess.fetch(&mut graph, &var).unwrap(), vec![5.0f32, 6.0].into());
+    assert_eq!(sess.fetch(&mut graph, &resource.read()).unwrap()[0], 7);
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn test_checkpoint_state_strings() {
+    let s = "dir/model \"a\"\\b";
+    assert_eq!(parse_text_string(&format_text_string(s)).unwrap(), s);
+    assert!(parse_text_string("model").is_err());
+  }
 }
\ No newline at end of file
@@ -1,14 +1,28 @@
+use super::control_flow::cond_input;
+use super::new_id;
+use super::ops;
+use super::sugar::constant;
+use super::Edge;
+use super::Edges;
 use super::Graph;
+use super::GraphEdge;
+use super::GraphOperation;
+use super::ImportGraphDefOptions;
+use super::Operation;
 use super::Output;
 use super::Result;
 use super::Status;
+use protobuf::Message;
+use std::collections::HashSet;
 use std::ffi::CString;
 use std::ffi::NulError;
 use std::mem;
 use std::os::raw::c_int;
 use std::ptr;
+use std::rc::Rc;
 use std::result;
 use std::slice;
+use tensorflow_protos::graph::GraphDef;
 use tensorflow_sys as tf;
 
 // This exists purely to ensure TF_AbortWhile gets called properly, even on panic.
@@ -170,6 +184,215 @@ impl<'a> WhileBuilder<'a> {
 
 ////////////////////////
 
+// The number of iterations the C API lets run in parallel.
+const DEFAULT_PARALLEL_ITERATIONS: u32 = 10;
+
+/// A TypedWhileBuilder is used to build a while loop whose loop variables are typed edges,
+/// so the condition and body can be written with the generated ops and operator sugar.
+///
+/// The loop variables can be any `Edges`, e.g. a tuple of edges of different types. The loop
+/// is added to the graph when one of its outputs is. Edges from outside the loop which are used
+/// by the condition or body are added to the loop's subgraphs as copies, so they must be computed
+/// from constants. Anything else, e.g. a placeholder or a variable's resource handle, must be
+/// passed in as a loop variable, and adding the loop fails if it isn't.
+pub struct TypedWhileBuilder<V: Edges> {
+    inputs: V,
+    cond: Rc<dyn Fn(V) -> Edge<bool>>,
+    body: Rc<dyn Fn(V) -> V>,
+    name: Option<String>,
+    maximum_iterations: Option<i32>,
+    parallel_iterations: u32,
+}
+
+impl<V: Edges> TypedWhileBuilder<V> {
+    /// Creates a TypedWhileBuilder, which repeatedly replaces the loop variables, starting
+    /// with `inputs`, by the result of `body` until `cond` returns false.
+    pub fn new<C, B>(inputs: V, cond: C, body: B) -> Self
+    where C: Fn(V) -> Edge<bool> + 'static,
+          B: Fn(V) -> V + 'static, {
+        Self {
+            inputs,
+            cond: Rc::new(cond),
+            body: Rc::new(body),
+            name: None,
+            maximum_iterations: None,
+            parallel_iterations: DEFAULT_PARALLEL_ITERATIONS,
+        }
+    }
+
+    /// Sets a unique name for this while loop. This is used as a prefix
+    /// for created operations. If not set, a unique prefix will be generated.
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Stops the loop after at most this many iterations, even if the condition is still true
+    pub fn maximum_iterations(&mut self, maximum_iterations: i32) -> &mut Self {
+        self.maximum_iterations = Some(maximum_iterations);
+        self
+    }
+
+    /// The number of iterations allowed to run in parallel. Defaults to 10.
+    ///
+    /// The C API always uses the default, so loops with a different setting are built in a
+    /// separate graph and imported with the setting changed. TensorFlow can't differentiate
+    /// such loops.
+    pub fn parallel_iterations(&mut self, parallel_iterations: u32) -> &mut Self {
+        self.parallel_iterations = parallel_iterations;
+        self
+    }
+
+    /// Returns the final values of the loop variables
+    pub fn finish(self) -> V {
+        let ids = (0..self.inputs.len()).map(|_| new_id()).collect();
+        let inputs = self.inputs.clone();
+        let while_op = Rc::new(TypedWhileOp { builder: self, ids });
+        let mut edges = (0..while_op.ids.len()).map(|index| {
+            let output: Rc<dyn GraphOperation> = Rc::new(TypedWhileOutput { while_op: while_op.clone(), index });
+            (output, 0)
+        });
+        inputs.with_edges(&mut edges)
+    }
+}
+
+// Returns the loop variables held by outputs, which are in a subgraph of the loop, after an
+// iteration counter if there is one.
+fn loop_variables<V: Edges>(template: &V, outputs: &[Output]) -> V {
+    let mut edges = outputs.iter().map(|output| {
+        let operation: Rc<dyn GraphOperation> = Rc::new(output.operation.clone());
+        (operation, output.index)
+    });
+    template.with_edges(&mut edges)
+}
+
+// Returns an error if a subgraph of the loop has an operation with no inputs other than a
+// constant or a loop variable, which must have been copied from outside the loop. The copy, e.g.
+// of a placeholder, wouldn't have the value of the original.
+fn check_captures(graph: &Graph, loop_variables: &[Output]) -> Result<()> {
+    let mut names = HashSet::new();
+    for output in loop_variables {
+        names.insert(output.operation.name()?);
+    }
+    for op in graph.operation_iter() {
+        if op.num_inputs() == 0 && op.op_type()? != "Const" && !names.contains(&op.name()?) {
+            return Err(invalid_arg!("The while loop uses the {} operation {} from outside the loop, \
+                                     which must be passed in as a loop variable instead",
+                                    op.op_type()?, op.name()?));
+        }
+    }
+    Ok(())
+}
+
+// Adds a typed while loop to the graph, recording the output for each loop variable.
+struct TypedWhileOp<V: Edges> {
+    builder: TypedWhileBuilder<V>,
+    ids: Vec<usize>,
+}
+
+impl<V: Edges> TypedWhileOp<V> {
+    fn add_to_graph(&self, graph: &mut Graph) -> Result<()> {
+        if self.ids.is_empty() || graph.get_op_by_id(self.ids[0]).is_some() {
+            return Ok(());
+        }
+        graph.outside_cond_contexts(self.ids[0], |graph| self.add_loop(graph))
+    }
+
+    fn add_loop(&self, graph: &mut Graph) -> Result<()> {
+        let builder = &self.builder;
+        let counted = builder.maximum_iterations.is_some();
+        let mut inputs = Vec::new();
+        if counted {
+            inputs.push(constant(0i32).output(graph)?);
+        }
+        inputs.extend(builder.inputs.outputs(graph)?);
+        let inputs = inputs.into_iter().map(|input| cond_input(graph, input)).collect::<Result<Vec<_>>>()?;
+        let skip = if counted { 1 } else { 0 };
+
+        let cond = |graph: &mut Graph, outputs: &[Output]| -> Result<Output> {
+            let mut keep_going = (builder.cond)(loop_variables(&builder.inputs, &outputs[skip..]));
+            if let Some(maximum_iterations) = builder.maximum_iterations {
+                let counter: Edge<i32> = loop_variables(&constant(0i32), &outputs[..1]);
+                keep_going = ops::LogicalAnd::new(ops::Less::new(counter, constant(maximum_iterations)), keep_going);
+            }
+            let output = keep_going.output(graph)?;
+            check_captures(graph, outputs)?;
+            Ok(output)
+        };
+        let body = |graph: &mut Graph, outputs: &[Output]| -> Result<Vec<Output>> {
+            let mut next = Vec::new();
+            if counted {
+                let counter: Edge<i32> = loop_variables(&constant(0i32), &outputs[..1]);
+                next.push((counter + constant(1i32)).output(graph)?);
+            }
+            next.extend((builder.body)(loop_variables(&builder.inputs, &outputs[skip..])).outputs(graph)?);
+            check_captures(graph, outputs)?;
+            Ok(next)
+        };
+        let name = match builder.name {
+            Some(ref name) => graph.scoped_name(name),
+            None => {
+                let index = graph.generate_operation_name(&graph.scoped_name("while_loop_{}/Merge"))?;
+                graph.scoped_name(&format!("while_loop_{}", index))
+            }
+        };
+
+        let outputs = if builder.parallel_iterations == DEFAULT_PARALLEL_ITERATIONS {
+            WhileBuilder::new(graph, cond, body, &inputs)?.name(&name)?.finish()?
+        } else {
+            // Build the loop in a separate graph, taking its inputs from placeholders, so the
+            // setting can be changed before importing it.
+            let mut loop_graph = Graph::new();
+            let mut placeholders = Vec::new();
+            let input_names: Vec<_> = (0..inputs.len()).map(|i| format!("{}/input_{}", name, i)).collect();
+            for (input, input_name) in inputs.iter().zip(&input_names) {
+                let mut placeholder = loop_graph.new_operation("Placeholder", input_name)?;
+                placeholder.set_attr_type("dtype", input.operation.output_type(input.index as usize))?;
+                placeholders.push(Output { operation: placeholder.finish()?, index: 0 });
+            }
+            let outputs = WhileBuilder::new(&mut loop_graph, cond, body, &placeholders)?.name(&name)?.finish()?;
+
+            let mut graph_def = GraphDef::new();
+            graph_def.merge_from_bytes(&loop_graph.graph_def()?)?;
+            for node in graph_def.mut_node().iter_mut() {
+                if node.get_op() == "Enter" || node.get_op() == "RefEnter" {
+                    let mut attr = node.get_attr()["parallel_iterations"].clone();
+                    attr.set_i(builder.parallel_iterations as i64);
+                    node.mut_attr().insert("parallel_iterations".to_string(), attr);
+                }
+            }
+            // The placeholders are still imported, but nothing uses them.
+            let mut options = ImportGraphDefOptions::new();
+            for (input, input_name) in inputs.iter().zip(&input_names) {
+                options.add_input_mapping(input_name, 0, input)?;
+            }
+            for output in &outputs {
+                options.add_return_output(&output.operation.name()?, output.index as usize)?;
+            }
+            graph.import_graph_def_with_return_outputs(&graph_def.write_to_bytes()?, &options)?
+        };
+
+        for (output, id) in outputs.into_iter().skip(skip).zip(&self.ids) {
+            graph.record_op(*id, output.operation);
+        }
+        Ok(())
+    }
+}
+
+struct TypedWhileOutput<V: Edges> {
+    while_op: Rc<TypedWhileOp<V>>,
+    index: usize,
+}
+
+impl<V: Edges> GraphOperation for TypedWhileOutput<V> {
+    fn tf_operation(&self, graph: &mut Graph) -> Result<Operation> {
+        self.while_op.add_to_graph(graph)?;
+        Ok(graph.get_op_by_id(self.while_op.ids[self.index]).unwrap())
+    }
+}
+
+////////////////////////
+
 #[cfg(test)]
 mod tests {
     use super::super::DataType;
@@ -178,6 +401,7 @@ mod tests {
     use super::super::SessionOptions;
     use super::super::SessionRunArgs;
     use super::super::Tensor;
+    use super::super::sugar;
     use super::*;
 
     fn constant(graph: &mut Graph, name: &str, value: i32) -> Operation {
@@ -258,4 +482,70 @@ mod tests {
             .finish()
             .unwrap();
     }
+
+    fn counted_doubling() -> TypedWhileBuilder<(Edge<i32>, Edge<f32>)> {
+        TypedWhileBuilder::new((sugar::constant(0i32), sugar::constant(1.0f32)),
+                               |(i, _)| ops::Less::new(i, sugar::constant(5i32)),
+                               |(i, acc)| (i + sugar::constant(1i32), acc * sugar::constant(2.0f32)))
+    }
+
+    #[test]
+    fn typed_while() {
+        let mut graph = Graph::new();
+        let (i, acc) = counted_doubling().finish();
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(session.fetch(&mut graph, &i).unwrap()[0], 5);
+        assert_eq!(session.fetch(&mut graph, &acc).unwrap()[0], 32.0);
+    }
+
+    #[test]
+    fn typed_while_maximum_iterations() {
+        let mut graph = Graph::new();
+        let mut builder = counted_doubling();
+        builder.name("limited").maximum_iterations(3);
+        let (i, acc) = builder.finish();
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(session.fetch(&mut graph, &i).unwrap()[0], 3);
+        assert_eq!(session.fetch(&mut graph, &acc).unwrap()[0], 8.0);
+    }
+
+    #[test]
+    fn typed_while_parallel_iterations() {
+        let mut graph = Graph::new();
+        let mut builder = counted_doubling();
+        builder.parallel_iterations(1);
+        let (i, acc) = builder.finish();
+        i.operation(&mut graph).unwrap();
+        let enters: Vec<_> = graph.operation_iter().filter(|op| op.op_type().unwrap() == "Enter").collect();
+        assert!(!enters.is_empty());
+        for enter in enters {
+            assert_eq!(enter.get_attr_int("parallel_iterations").unwrap(), 1);
+        }
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        assert_eq!(session.fetch(&mut graph, &acc).unwrap()[0], 32.0);
+    }
+
+    #[test]
+    fn typed_while_captured_placeholder() {
+        let mut graph = Graph::new();
+        let limit = ops::Placeholder::<i32>::new();
+        let i = TypedWhileBuilder::new(sugar::constant(0i32),
+                                       move |i| ops::Less::new(i, limit.clone()),
+                                       |i| i + sugar::constant(1i32)).finish();
+        assert!(i.operation(&mut graph).is_err());
+
+        // Passed in as a loop variable, the placeholder is used.
+        let limit = ops::Placeholder::<i32>::new();
+        let (i, _) = TypedWhileBuilder::new((sugar::constant(0i32), limit.clone()),
+                                            |(i, limit)| ops::Less::new(i, limit),
+                                            |(i, limit)| (i + sugar::constant(1i32), limit)).finish();
+        let limit = limit.output(&mut graph).unwrap();
+        let i = i.output(&mut graph).unwrap();
+        let session = Session::new(&SessionOptions::new(), &graph).unwrap();
+        let mut step = SessionRunArgs::new();
+        step.add_feed(&limit.operation, limit.index, Tensor::from(4i32));
+        let token = step.request_fetch(&i.operation, i.index);
+        session.run(&mut step).unwrap();
+        assert_eq!(step.fetch::<i32>(token).unwrap()[0], 4);
+    }
 }
@@ -7,7 +7,18 @@ fn main() {
                "protos/tensorflow/core/framework/types.proto",
                "protos/tensorflow/core/framework/tensor_shape.proto",
                "protos/tensorflow/core/framework/tensor.proto",
-               "protos/tensorflow/core/framework/resource_handle.proto"];
+               "protos/tensorflow/core/framework/resource_handle.proto",
+               "protos/tensorflow/core/example/feature.proto",
+               "protos/tensorflow/core/example/example.proto",
+               "protos/tensorflow/core/framework/versions.proto",
+               "protos/tensorflow/core/framework/tensor_slice.proto",
+               "protos/tensorflow/core/protobuf/tensor_bundle.proto",
+               "protos/tensorflow/core/framework/node_def.proto",
+               "protos/tensorflow/core/framework/function.proto",
+               "protos/tensorflow/core/framework/graph.proto",
+               "protos/tensorflow/core/protobuf/saver.proto",
+               "protos/tensorflow/core/protobuf/meta_graph.proto",
+               "protos/tensorflow/core/protobuf/saved_model.proto"];
 
   for file in &files {
     println!("cargo:rerun-if-changed={}", file);
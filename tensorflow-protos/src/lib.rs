@@ -4,3 +4,14 @@ pub mod types;
 pub mod tensor_shape;
 pub mod tensor;
 pub mod resource_handle;
+pub mod feature;
+pub mod example;
+pub mod versions;
+pub mod tensor_slice;
+pub mod tensor_bundle;
+pub mod node_def;
+pub mod function;
+pub mod graph;
+pub mod saver;
+pub mod meta_graph;
+pub mod saved_model;
@@ -0,0 +1,608 @@
+// This file is generated by rust-protobuf 2.5.0. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NodeDef {
+    // message fields
+    pub name: ::std::string::String,
+    pub op: ::std::string::String,
+    pub input: ::protobuf::RepeatedField<::std::string::String>,
+    pub device: ::std::string::String,
+    pub attr: ::std::collections::HashMap<::std::string::String, super::attr_value::AttrValue>,
+    pub experimental_debug_info: ::protobuf::SingularPtrField<NodeDef_ExperimentalDebugInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodeDef {
+    fn default() -> &'a NodeDef {
+        <NodeDef as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeDef {
+    pub fn new() -> NodeDef {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string op = 2;
+
+
+    pub fn get_op(&self) -> &str {
+        &self.op
+    }
+    pub fn clear_op(&mut self) {
+        self.op.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_op(&mut self, v: ::std::string::String) {
+        self.op = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_op(&mut self) -> &mut ::std::string::String {
+        &mut self.op
+    }
+
+    // Take field
+    pub fn take_op(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.op, ::std::string::String::new())
+    }
+
+    // repeated string input = 3;
+
+
+    pub fn get_input(&self) -> &[::std::string::String] {
+        &self.input
+    }
+    pub fn clear_input(&mut self) {
+        self.input.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.input = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_input(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.input
+    }
+
+    // Take field
+    pub fn take_input(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.input, ::protobuf::RepeatedField::new())
+    }
+
+    // string device = 4;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // repeated .tensorflow.NodeDef.attr_MapEntry attr = 5;
+
+
+    pub fn get_attr(&self) -> &::std::collections::HashMap<::std::string::String, super::attr_value::AttrValue> {
+        &self.attr
+    }
+    pub fn clear_attr(&mut self) {
+        self.attr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attr(&mut self, v: ::std::collections::HashMap<::std::string::String, super::attr_value::AttrValue>) {
+        self.attr = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attr(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, super::attr_value::AttrValue> {
+        &mut self.attr
+    }
+
+    // Take field
+    pub fn take_attr(&mut self) -> ::std::collections::HashMap<::std::string::String, super::attr_value::AttrValue> {
+        ::std::mem::replace(&mut self.attr, ::std::collections::HashMap::new())
+    }
+
+    // .tensorflow.NodeDef.ExperimentalDebugInfo experimental_debug_info = 6;
+
+
+    pub fn get_experimental_debug_info(&self) -> &NodeDef_ExperimentalDebugInfo {
+        self.experimental_debug_info.as_ref().unwrap_or_else(|| NodeDef_ExperimentalDebugInfo::default_instance())
+    }
+    pub fn clear_experimental_debug_info(&mut self) {
+        self.experimental_debug_info.clear();
+    }
+
+    pub fn has_experimental_debug_info(&self) -> bool {
+        self.experimental_debug_info.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_experimental_debug_info(&mut self, v: NodeDef_ExperimentalDebugInfo) {
+        self.experimental_debug_info = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_experimental_debug_info(&mut self) -> &mut NodeDef_ExperimentalDebugInfo {
+        if self.experimental_debug_info.is_none() {
+            self.experimental_debug_info.set_default();
+        }
+        self.experimental_debug_info.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_experimental_debug_info(&mut self) -> NodeDef_ExperimentalDebugInfo {
+        self.experimental_debug_info.take().unwrap_or_else(|| NodeDef_ExperimentalDebugInfo::new())
+    }
+}
+
+impl ::protobuf::Message for NodeDef {
+    fn is_initialized(&self) -> bool {
+        for v in &self.experimental_debug_info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.op)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.input)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::attr_value::AttrValue>>(wire_type, is, &mut self.attr)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.experimental_debug_info)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.op.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.op);
+        }
+        for value in &self.input {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.device);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::attr_value::AttrValue>>(5, &self.attr);
+        if let Some(ref v) = self.experimental_debug_info.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.op.is_empty() {
+            os.write_string(2, &self.op)?;
+        }
+        for v in &self.input {
+            os.write_string(3, &v)?;
+        };
+        if !self.device.is_empty() {
+            os.write_string(4, &self.device)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::attr_value::AttrValue>>(5, &self.attr, os)?;
+        if let Some(ref v) = self.experimental_debug_info.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NodeDef {
+        NodeDef::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &NodeDef| { &m.name },
+                    |m: &mut NodeDef| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "op",
+                    |m: &NodeDef| { &m.op },
+                    |m: &mut NodeDef| { &mut m.op },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "input",
+                    |m: &NodeDef| { &m.input },
+                    |m: &mut NodeDef| { &mut m.input },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &NodeDef| { &m.device },
+                    |m: &mut NodeDef| { &mut m.device },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::attr_value::AttrValue>>(
+                    "attr",
+                    |m: &NodeDef| { &m.attr },
+                    |m: &mut NodeDef| { &mut m.attr },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<NodeDef_ExperimentalDebugInfo>>(
+                    "experimental_debug_info",
+                    |m: &NodeDef| { &m.experimental_debug_info },
+                    |m: &mut NodeDef| { &mut m.experimental_debug_info },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<NodeDef>(
+                    "NodeDef",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static NodeDef {
+        static mut instance: ::protobuf::lazy::Lazy<NodeDef> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const NodeDef,
+        };
+        unsafe {
+            instance.get(NodeDef::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for NodeDef {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.op.clear();
+        self.input.clear();
+        self.device.clear();
+        self.attr.clear();
+        self.experimental_debug_info.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NodeDef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeDef {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NodeDef_ExperimentalDebugInfo {
+    // message fields
+    pub original_node_names: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodeDef_ExperimentalDebugInfo {
+    fn default() -> &'a NodeDef_ExperimentalDebugInfo {
+        <NodeDef_ExperimentalDebugInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeDef_ExperimentalDebugInfo {
+    pub fn new() -> NodeDef_ExperimentalDebugInfo {
+        ::std::default::Default::default()
+    }
+
+    // repeated string original_node_names = 1;
+
+
+    pub fn get_original_node_names(&self) -> &[::std::string::String] {
+        &self.original_node_names
+    }
+    pub fn clear_original_node_names(&mut self) {
+        self.original_node_names.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_original_node_names(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.original_node_names = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_original_node_names(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.original_node_names
+    }
+
+    // Take field
+    pub fn take_original_node_names(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.original_node_names, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for NodeDef_ExperimentalDebugInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.original_node_names)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.original_node_names {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.original_node_names {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NodeDef_ExperimentalDebugInfo {
+        NodeDef_ExperimentalDebugInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "original_node_names",
+                    |m: &NodeDef_ExperimentalDebugInfo| { &m.original_node_names },
+                    |m: &mut NodeDef_ExperimentalDebugInfo| { &mut m.original_node_names },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<NodeDef_ExperimentalDebugInfo>(
+                    "NodeDef_ExperimentalDebugInfo",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static NodeDef_ExperimentalDebugInfo {
+        static mut instance: ::protobuf::lazy::Lazy<NodeDef_ExperimentalDebugInfo> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const NodeDef_ExperimentalDebugInfo,
+        };
+        unsafe {
+            instance.get(NodeDef_ExperimentalDebugInfo::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for NodeDef_ExperimentalDebugInfo {
+    fn clear(&mut self) {
+        self.original_node_names.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NodeDef_ExperimentalDebugInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeDef_ExperimentalDebugInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n(tensorflow/core/framework/node_def.proto\x12\ntensorflow\"\xcb\x02\n\
+    \x07NodeDef\x12\x0e\n\x04name\x18\x01\x20\x01(\tB\0\x12\x0c\n\x02op\x18\
+    \x02\x20\x01(\tB\0\x12\x0f\n\x05input\x18\x03\x20\x03(\tB\0\x12\x10\n\
+    \x06device\x18\x04\x20\x01(\tB\0\x121\n\x04attr\x18\x05\x20\x03(\x0b2!.t\
+    ensorflow.NodeDef.attr_MapEntryB\0\x12L\n\x17experimental_debug_info\x18\
+    \x06\x20\x01(\x0b2).tensorflow.NodeDef.ExperimentalDebugInfoB\0\x1a8\n\
+    \x15ExperimentalDebugInfo\x12\x1d\n\x13original_node_names\x18\x01\x20\
+    \x03(\tB\0:\0\x1aB\n\rattr_MapEntry\x12\t\n\x03key\x18\x01(\t\x12\"\n\
+    \x05value\x18\x02(\x0b2\x15.tensorflow.AttrValue:\x028\x01:\0B\0b\x06pro\
+    to3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}